@@ -59,6 +59,25 @@ fn generate_tests_from_spec() {
         let spec = Spec::new(&raw_spec);
         let mut n_tests = 0;
 
+        // Extensions that change how plain CommonMark parses are only enabled
+        // for their own spec, or for one named after several of them, like
+        // mentions_autolink
+        let mut options = vec!["OPTION_ENABLE_TABLES", "OPTION_ENABLE_FOOTNOTES"];
+        for extension in spec_name.split('_') {
+            match extension {
+                "strikethrough" => options.push("OPTION_ENABLE_STRIKETHROUGH"),
+                "tasklists" => options.push("OPTION_ENABLE_TASKLISTS"),
+                "mentions" => options.push("OPTION_ENABLE_MENTIONS"),
+                "emoji" => options.push("OPTION_ENABLE_EMOJI"),
+                "spoilers" => options.push("OPTION_ENABLE_SPOILERS"),
+                "autolink" => options.push("OPTION_ENABLE_AUTOLINK"),
+                _ => ()
+            }
+        }
+        let inserts: String = options.iter()
+                                     .map(|o| format!("\n        opts.insert({});", o))
+                                     .collect();
+
         spec_rs.write(b"// This file is auto-generated by the build script\n").unwrap();
         spec_rs.write(b"// Please, do not modify it manually\n").unwrap();
         spec_rs.write(b"\nextern crate pulldown_cmark;\n").unwrap();
@@ -73,13 +92,11 @@ fn generate_tests_from_spec() {
         let original = r##"{original}"##;
         let expected = r##"{expected}"##;
 
-        use pulldown_cmark::{{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES}};
+        use pulldown_cmark::{{Parser, html, Options, {options}}};
 
         let mut s = String::new();
 
-        let mut opts = Options::empty();
-        opts.insert(OPTION_ENABLE_TABLES);
-        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        let mut opts = Options::empty();{inserts}
 
         let p = Parser::new_ext(&original, opts);
         html::push_html(&mut s, p);
@@ -89,7 +106,9 @@ fn generate_tests_from_spec() {
                     spec_name,
                     i=i+1,
                     original=testcase.original,
-                    expected=testcase.expected
+                    expected=testcase.expected,
+                    options=options.join(", "),
+                    inserts=inserts
                 ),
             ).unwrap();
 
@@ -0,0 +1,480 @@
+// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! ANSI terminal renderer that takes an iterator of events as input.
+
+use std::fmt::Write;
+
+use render::{BULLETS, is_printable};
+use parse::Event::{Start, End, Text, Html, InlineHtml, SoftBreak, HardBreak, FootnoteReference,
+    TaskListMarker, Mention, Emoji};
+use parse::{Event, Tag};
+
+/// Options for `push_ansi_with`.
+#[derive(Clone, Debug)]
+pub struct AnsiOptions {
+    /// Column at which text is wrapped; `None` keeps the source line breaks.
+    /// Code blocks are never wrapped.
+    pub width: Option<usize>,
+    /// Whether links are emitted as OSC 8 hyperlinks. Terminals without
+    /// support for them just show the link text.
+    pub hyperlinks: bool,
+}
+
+impl Default for AnsiOptions {
+    fn default() -> AnsiOptions {
+        AnsiOptions {
+            width: None,
+            hyperlinks: true,
+        }
+    }
+}
+
+// SGR parameters for each kind of span
+const BOLD: &'static str = "1";
+const DIM: &'static str = "2";
+const ITALIC: &'static str = "3";
+const STRIKETHROUGH: &'static str = "9";
+const UNDERLINED_BOLD: &'static str = "1;4";
+const CODE: &'static str = "36";
+const LINK: &'static str = "4;34";
+const MENTION: &'static str = "1;34";
+const CONCEALED: &'static str = "8";
+
+// What goes in front of each line inside a container block.
+enum Prefix {
+    Quote,
+    Indent(usize),
+}
+
+struct Ctx<'b, 'c, I> {
+    iter: I,
+    buf: &'b mut String,
+    opts: &'c AnsiOptions,
+    // SGR parameters of the open spans, reapplied after every reset
+    styles: Vec<&'static str>,
+    prefixes: Vec<Prefix>,
+    // next item number for each open list, `None` for bullet lists
+    lists: Vec<Option<usize>>,
+    // visible column of the end of the buffer
+    col: usize,
+    // a space is owed before the next word, unless the line is wrapped there
+    pending_space: bool,
+    // escape sequences that go after the pending space
+    pending_escapes: String,
+    // nothing has been written since the last container or cell started
+    block_start: bool,
+    // the last line written is blank
+    blank: bool,
+    in_code_block: bool,
+    // footnote references seen so far, for numbering them
+    footnotes: Vec<String>,
+}
+
+impl<'a, 'b, 'c, I: Iterator<Item=Event<'a>>> Ctx<'b, 'c, I> {
+    fn escape(&mut self, seq: &str) {
+        if self.pending_space {
+            self.pending_escapes.push_str(seq);
+        } else {
+            self.buf.push_str(seq);
+        }
+    }
+
+    fn reapply_styles(&mut self) {
+        let mut seq = "\x1b[0m".to_string();
+        if !self.styles.is_empty() {
+            let _ = write!(seq, "\x1b[{}m", self.styles.join(";"));
+        }
+        self.escape(&seq);
+    }
+
+    fn start_style(&mut self, style: &'static str) {
+        self.styles.push(style);
+        self.escape(&format!("\x1b[{}m", style));
+    }
+
+    fn end_style(&mut self) {
+        self.styles.pop();
+        // the reset belongs before any pending space, unless a start is already queued
+        if self.pending_escapes.is_empty() {
+            let pending = self.pending_space;
+            self.pending_space = false;
+            self.reapply_styles();
+            self.pending_space = pending;
+        } else {
+            self.reapply_styles();
+        }
+    }
+
+    fn newline(&mut self) {
+        let escapes = ::std::mem::replace(&mut self.pending_escapes, String::new());
+        self.buf.push_str(&escapes);
+        self.pending_space = false;
+        self.buf.push('\n');
+        self.col = 0;
+    }
+
+    fn fresh_line(&mut self) {
+        if self.col > 0 {
+            self.newline();
+        }
+    }
+
+    // Separate a block from the one before it with a blank line.
+    fn blank_line(&mut self) {
+        if self.buf.is_empty() || self.block_start {
+            return;
+        }
+        self.fresh_line();
+        if !self.blank {
+            self.write_prefix(true);
+            self.newline();
+            self.blank = true;
+        }
+    }
+
+    fn write_prefix(&mut self, blank: bool) {
+        let mut quoted = false;
+        for i in 0..self.prefixes.len() {
+            match self.prefixes[i] {
+                Prefix::Quote => {
+                    let last = i + 1 == self.prefixes.len();
+                    self.buf.push_str("\x1b[0;2m│\x1b[0m");
+                    if !(blank && last) {
+                        self.buf.push(' ');
+                    }
+                    self.col += 2;
+                    quoted = true;
+                }
+                Prefix::Indent(width) => {
+                    if !blank {
+                        for _ in 0..width {
+                            self.buf.push(' ');
+                        }
+                    }
+                    self.col += width;
+                }
+            }
+        }
+        if quoted && !self.styles.is_empty() && !blank {
+            let _ = write!(self.buf, "\x1b[{}m", self.styles.join(";"));
+        }
+    }
+
+    fn start_content(&mut self) {
+        if self.col == 0 {
+            self.write_prefix(false);
+        }
+        self.block_start = false;
+        self.blank = false;
+    }
+
+    fn space(&mut self) {
+        if self.col > 0 && !self.block_start {
+            self.pending_space = true;
+        }
+    }
+
+    fn word(&mut self, word: &str) {
+        self.start_content();
+        let len = word.chars().filter(|&c| is_printable(c)).count();
+        if self.pending_space {
+            let wrap = match self.opts.width {
+                Some(width) => self.col + 1 + len > width,
+                None => false,
+            };
+            if wrap {
+                self.newline();
+                self.write_prefix(false);
+            } else {
+                self.buf.push(' ');
+                self.col += 1;
+            }
+            let escapes = ::std::mem::replace(&mut self.pending_escapes, String::new());
+            self.buf.push_str(&escapes);
+            self.pending_space = false;
+        }
+        self.buf.extend(word.chars().filter(|&c| is_printable(c)));
+        self.col += len;
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.in_code_block {
+            for (i, line) in text.split('\n').enumerate() {
+                if i > 0 {
+                    self.newline();
+                }
+                if !line.is_empty() {
+                    self.start_content();
+                    self.buf.extend(line.chars().filter(|&c| is_printable(c)));
+                    self.col += line.chars().filter(|&c| is_printable(c)).count();
+                }
+            }
+            return;
+        }
+        for (i, word) in text.split(' ').enumerate() {
+            if i > 0 {
+                self.space();
+            }
+            if !word.is_empty() {
+                self.word(word);
+            }
+        }
+    }
+
+    pub fn run(&mut self) {
+        while let Some(event) = self.iter.next() {
+            match event {
+                Start(tag) => self.start_tag(tag),
+                End(tag) => self.end_tag(tag),
+                Text(text) => self.text(&text),
+                // a terminal can't render raw HTML, and its source is noise
+                Html(_) | InlineHtml(_) => (),
+                SoftBreak => match self.opts.width {
+                    Some(_) => self.space(),
+                    None => self.newline(),
+                },
+                HardBreak => self.newline(),
+                FootnoteReference(name) => {
+                    let number = match self.footnotes.iter().position(|n| *n == *name) {
+                        Some(i) => i + 1,
+                        None => {
+                            self.footnotes.push(name.into_owned());
+                            self.footnotes.len()
+                        }
+                    };
+                    self.word(&format!("[{}]", number));
+                }
+                // part of the item marker, so the item's lines line up after it
+                TaskListMarker(checked) => {
+                    self.start_content();
+                    self.buf.push_str(if checked { "☑ " } else { "☐ " });
+                    self.col += 2;
+                    if let Some(&mut Prefix::Indent(ref mut width)) = self.prefixes.last_mut() {
+                        *width += 2;
+                    }
+                    self.block_start = true;
+                }
+                Mention(kind, name) => {
+                    self.start_style(MENTION);
+                    self.word(&format!("{}{}", kind.sigil(), name));
+                    self.end_style();
+                }
+                Emoji(_, emoji) => self.text(&emoji),
+            }
+        }
+        if !self.styles.is_empty() {
+            self.styles.clear();
+            self.buf.push_str("\x1b[0m");
+        }
+        self.fresh_line();
+    }
+
+    fn start_tag(&mut self, tag: Tag<'a>) {
+        match tag {
+            Tag::Paragraph | Tag::Table(_) => self.blank_line(),
+            Tag::Rule => {
+                self.blank_line();
+                self.start_content();
+                let width = self.opts.width.unwrap_or(20).saturating_sub(self.col);
+                let mut rule = String::new();
+                for _ in 0..width {
+                    rule.push('─');
+                }
+                let _ = write!(self.buf, "\x1b[{}m{}", DIM, rule);
+                self.col += width;
+                self.reapply_styles();
+                self.newline();
+            }
+            Tag::Header(level) => {
+                self.blank_line();
+                self.start_style(if level == 1 { UNDERLINED_BOLD } else { BOLD });
+            }
+            Tag::CodeBlock(_) => {
+                self.blank_line();
+                self.prefixes.push(Prefix::Indent(2));
+                self.start_style(CODE);
+                self.in_code_block = true;
+            }
+            Tag::BlockQuote => {
+                self.blank_line();
+                self.prefixes.push(Prefix::Quote);
+                self.block_start = true;
+            }
+            Tag::List(start) => {
+                if self.lists.is_empty() {
+                    self.blank_line();
+                } else {
+                    self.fresh_line();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.fresh_line();
+                let marker = match self.lists.last_mut() {
+                    Some(&mut Some(ref mut number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => format!("{} ", BULLETS[(self.lists.len() - 1) % BULLETS.len()]),
+                };
+                self.start_content();
+                self.buf.push_str(&marker);
+                let width = marker.chars().count();
+                self.col += width;
+                self.prefixes.push(Prefix::Indent(width));
+                self.block_start = true;
+            }
+            Tag::TableHead => {
+                self.fresh_line();
+                self.start_style(BOLD);
+            }
+            Tag::TableRow => self.fresh_line(),
+            Tag::TableCell => {
+                if !self.block_start && self.col > 0 {
+                    self.pending_space = true;
+                    self.word("│");
+                    self.space();
+                }
+                self.block_start = true;
+            }
+            Tag::FootnoteDefinition(name) => {
+                self.blank_line();
+                let number = match self.footnotes.iter().position(|n| *n == *name) {
+                    Some(i) => i + 1,
+                    None => {
+                        self.footnotes.push(name.into_owned());
+                        self.footnotes.len()
+                    }
+                };
+                let marker = format!("[{}] ", number);
+                self.start_content();
+                self.buf.push_str(&marker);
+                let width = marker.chars().count();
+                self.col += width;
+                self.prefixes.push(Prefix::Indent(width));
+                self.block_start = true;
+            }
+            Tag::Emphasis => self.start_style(ITALIC),
+            Tag::Strong => self.start_style(BOLD),
+            Tag::Strikethrough => self.start_style(STRIKETHROUGH),
+            Tag::Spoiler => self.start_style(CONCEALED),
+            Tag::Code => self.start_style(CODE),
+            Tag::Link(dest, _) | Tag::Image(dest, _) => {
+                self.start_style(LINK);
+                if self.opts.hyperlinks {
+                    let dest: String = dest.chars().filter(|&c| is_printable(c)).collect();
+                    self.escape(&format!("\x1b]8;;{}\x1b\\", dest));
+                }
+            }
+        }
+    }
+
+    fn end_tag(&mut self, tag: Tag) {
+        match tag {
+            Tag::Header(_) | Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Spoiler |
+            Tag::Code => {
+                self.end_style()
+            }
+            Tag::TableHead => {
+                self.end_style();
+                self.fresh_line();
+            }
+            Tag::TableCell => self.pending_space = false,
+            Tag::CodeBlock(_) => {
+                self.in_code_block = false;
+                self.end_style();
+                self.prefixes.pop();
+                self.fresh_line();
+            }
+            Tag::BlockQuote | Tag::Item | Tag::FootnoteDefinition(_) => {
+                self.fresh_line();
+                self.prefixes.pop();
+                self.block_start = false;
+            }
+            Tag::List(_) => {
+                self.lists.pop();
+                self.fresh_line();
+            }
+            Tag::Link(_, _) | Tag::Image(_, _) => {
+                if self.opts.hyperlinks {
+                    self.escape("\x1b]8;;\x1b\\");
+                }
+                self.end_style();
+            }
+            _ => ()
+        }
+    }
+}
+
+/// Iterate over an `Iterator` of `Event`s, generate text styled with ANSI
+/// escape sequences for each `Event`, and push it to a `String`.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{ansi, Parser};
+///
+/// let parser = Parser::new("Some **bold** text");
+///
+/// let mut out = String::new();
+/// ansi::push_ansi(&mut out, parser);
+///
+/// assert_eq!(out, "Some \x1b[1mbold\x1b[0m text\n");
+/// ```
+pub fn push_ansi<'a, I: Iterator<Item=Event<'a>>>(buf: &mut String, iter: I) {
+    push_ansi_with(buf, iter, &AnsiOptions::default());
+}
+
+/// Like `push_ansi`, but rendered according to `opts`.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{ansi, Parser};
+/// use pulldown_cmark::ansi::AnsiOptions;
+///
+/// let opts = AnsiOptions { width: Some(12), hyperlinks: false };
+/// let parser = Parser::new("A paragraph wrapped at twelve columns.");
+///
+/// let mut out = String::new();
+/// ansi::push_ansi_with(&mut out, parser, &opts);
+///
+/// assert_eq!(out, "A paragraph\nwrapped at\ntwelve\ncolumns.\n");
+/// ```
+pub fn push_ansi_with<'a, I>(buf: &mut String, iter: I, opts: &AnsiOptions)
+        where I: Iterator<Item=Event<'a>> {
+    let mut ctx = Ctx {
+        iter: iter,
+        buf: buf,
+        opts: opts,
+        styles: Vec::new(),
+        prefixes: Vec::new(),
+        lists: Vec::new(),
+        col: 0,
+        pending_space: false,
+        pending_escapes: String::new(),
+        block_start: true,
+        blank: false,
+        in_code_block: false,
+        footnotes: Vec::new(),
+    };
+    ctx.run();
+}
@@ -0,0 +1,306 @@
+// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! BBCode renderer that takes an iterator of events as input, for forums.
+
+use std::fmt::Write;
+
+use render::{self, FootnoteNumbers};
+use parse::Event::{Start, End, Text, Html, InlineHtml, SoftBreak, HardBreak, FootnoteReference,
+    TaskListMarker, Mention, Emoji};
+use parse::{Event, Tag};
+
+// Forums don't look for tags inside a code block, except the `[/code]` that
+// ends it, so a zero-width space breaks up any in the code itself.
+fn push_code(buf: &mut String, code: &str) {
+    let mut mark = 0;
+    while let Some(pos) = code[mark..].find("[/") {
+        let i = mark + pos + 2;
+        buf.push_str(&code[mark..i]);
+        if code.get(i..i + 4).map_or(false, |tag| tag.eq_ignore_ascii_case("code")) {
+            buf.push('\u{200b}');
+        }
+        mark = i;
+    }
+    buf.push_str(&code[mark..]);
+}
+
+// Everywhere else a `[` followed by a name starts a tag, so a
+// zero-width space after it keeps text that only looks like one as text.
+fn push_text(buf: &mut String, text: &str) {
+    let mut mark = 0;
+    while let Some(pos) = text[mark..].find('[') {
+        let i = mark + pos + 1;
+        buf.push_str(&text[mark..i]);
+        match text[i..].chars().next() {
+            Some(c) if c == '/' || c == '*' || c.is_ascii_alphabetic() => buf.push('\u{200b}'),
+            _ => ()
+        }
+        mark = i;
+    }
+    buf.push_str(&text[mark..]);
+}
+
+struct Ctx<'a, 'b, I> {
+    iter: I,
+    buf: &'b mut String,
+    // buffer offset where the text of each open link begins
+    links: Vec<usize>,
+    // number of open lists
+    lists: usize,
+    // buffer offset at which a block needs no blank line in front of it
+    block_start: usize,
+    first_cell: bool,
+    cell_start: usize,
+    numbers: FootnoteNumbers<'a>,
+}
+
+// URLs go inside the tag, so the brackets and spaces that would end it early
+// are percent-encoded.
+fn push_url(buf: &mut String, url: &str) {
+    for c in url.chars() {
+        match c {
+            '[' => buf.push_str("%5B"),
+            ']' => buf.push_str("%5D"),
+            ' ' => buf.push_str("%20"),
+            '"' => buf.push_str("%22"),
+            c => buf.push(c),
+        }
+    }
+}
+
+impl<'a, 'b, I: Iterator<Item=Event<'a>>> Ctx<'a, 'b, I> {
+    pub fn run(&mut self) {
+        let start = self.buf.len();
+        while let Some(event) = self.iter.next() {
+            match event {
+                Start(tag) => self.start_tag(tag),
+                End(tag) => self.end_tag(tag),
+                Text(text) => push_text(self.buf, &text),
+                // forums don't take raw HTML
+                Html(_) | InlineHtml(_) => (),
+                SoftBreak => self.buf.push(' '),
+                HardBreak => self.buf.push('\n'),
+                FootnoteReference(name) => {
+                    let number = render::footnote_number(&mut self.numbers, name);
+                    let _ = write!(self.buf, "[{}]", number);
+                }
+                TaskListMarker(checked) => {
+                    self.buf.push_str(if checked { "☑ " } else { "☐ " });
+                    self.block_start = self.buf.len();
+                }
+                Mention(kind, name) => {
+                    self.buf.push(kind.sigil());
+                    push_text(self.buf, &name);
+                }
+                Emoji(_, emoji) => push_text(self.buf, &emoji),
+            }
+        }
+        render::trim_end_from(self.buf, start);
+    }
+
+    fn start_tag(&mut self, tag: Tag<'a>) {
+        match tag {
+            Tag::Paragraph | Tag::Table(_) => render::blank_line(self.buf, self.block_start),
+            Tag::Header(_) => {
+                render::blank_line(self.buf, self.block_start);
+                self.buf.push_str("[b]");
+            }
+            Tag::Rule => {
+                render::blank_line(self.buf, self.block_start);
+                self.buf.push_str("----------\n");
+            }
+            Tag::CodeBlock(_) => {
+                render::blank_line(self.buf, self.block_start);
+                self.buf.push_str("[code]");
+                let code = self.collect_text();
+                push_code(self.buf, code.trim_end_matches('\n'));
+                self.buf.push_str("[/code]\n");
+            }
+            Tag::BlockQuote => {
+                render::blank_line(self.buf, self.block_start);
+                self.buf.push_str("[quote]");
+                self.block_start = self.buf.len();
+            }
+            Tag::List(start) => {
+                if self.lists == 0 {
+                    render::blank_line(self.buf, self.block_start);
+                } else {
+                    render::fresh_line(self.buf);
+                }
+                self.lists += 1;
+                // forums only number lists from one
+                self.buf.push_str(if start.is_some() { "[list=1]\n" } else { "[list]\n" });
+            }
+            Tag::Item => {
+                render::fresh_line(self.buf);
+                self.buf.push_str("[*]");
+                self.block_start = self.buf.len();
+            }
+            Tag::TableHead => {
+                render::fresh_line(self.buf);
+                self.buf.push_str("[b]");
+                self.first_cell = true;
+            }
+            Tag::TableRow => {
+                render::fresh_line(self.buf);
+                self.first_cell = true;
+            }
+            Tag::TableCell => {
+                if !self.first_cell {
+                    self.buf.push_str(" | ");
+                }
+                self.first_cell = false;
+                self.cell_start = self.buf.len();
+            }
+            Tag::FootnoteDefinition(name) => {
+                render::blank_line(self.buf, self.block_start);
+                let number = render::footnote_number(&mut self.numbers, name);
+                let _ = write!(self.buf, "[{}] ", number);
+                self.block_start = self.buf.len();
+            }
+            Tag::Emphasis => self.buf.push_str("[i]"),
+            Tag::Strong => self.buf.push_str("[b]"),
+            Tag::Strikethrough => self.buf.push_str("[s]"),
+            Tag::Spoiler => self.buf.push_str("[spoiler]"),
+            // there's no inline code tag every forum knows
+            Tag::Code => {
+                let code = self.collect_text();
+                self.buf.push('`');
+                push_text(self.buf, &code);
+                self.buf.push('`');
+            }
+            Tag::Link(_, _) => {
+                let start = self.buf.len();
+                self.links.push(start);
+            }
+            // the alt text has nowhere to go
+            Tag::Image(dest, _) => {
+                self.collect_text();
+                self.buf.push_str("[img]");
+                push_url(self.buf, &dest);
+                self.buf.push_str("[/img]");
+            }
+        }
+    }
+
+    fn end_tag(&mut self, tag: Tag) {
+        match tag {
+            Tag::Header(_) | Tag::Strong | Tag::TableHead => self.buf.push_str("[/b]"),
+            Tag::BlockQuote => {
+                let len = self.buf.trim_end().len();
+                self.buf.truncate(len);
+                self.buf.push_str("[/quote]\n");
+            }
+            Tag::TableCell => {
+                let cell = self.buf.split_off(self.cell_start);
+                self.buf.push_str(cell.trim());
+            }
+            Tag::List(_) => {
+                self.lists -= 1;
+                render::fresh_line(self.buf);
+                self.buf.push_str("[/list]\n");
+            }
+            Tag::Emphasis => self.buf.push_str("[/i]"),
+            Tag::Strikethrough => self.buf.push_str("[/s]"),
+            Tag::Spoiler => self.buf.push_str("[/spoiler]"),
+            // wrap the link text, now that it's written
+            Tag::Link(dest, _) => {
+                if let Some(start) = self.links.pop() {
+                    let text = self.buf.split_off(start);
+                    if text.is_empty() || text == dest {
+                        self.buf.push_str("[url]");
+                        push_url(self.buf, &dest);
+                    } else {
+                        self.buf.push_str("[url=");
+                        push_url(self.buf, &dest);
+                        self.buf.push(']');
+                        self.buf.push_str(&text);
+                    }
+                    self.buf.push_str("[/url]");
+                }
+            }
+            _ => ()
+        }
+    }
+
+    // The text up to the end of the current tag, consuming the end tag too.
+    fn collect_text(&mut self) -> String {
+        let mut text = String::new();
+        let mut nest = 0;
+        while let Some(event) = self.iter.next() {
+            match event {
+                Start(_) => nest += 1,
+                End(_) if nest == 0 => break,
+                End(_) => nest -= 1,
+                Text(more) | Emoji(_, more) => text.push_str(&more),
+                _ => (),
+            }
+        }
+        text
+    }
+}
+
+/// Iterate over an `Iterator` of `Event`s, generate BBCode for each `Event`,
+/// and push it to a `String`.
+///
+/// Only the tags nearly every forum supports are used: `[b]`, `[i]`, `[s]`,
+/// `[code]`, `[quote]`, `[url]`, `[img]` and `[list]`, plus `[spoiler]` for
+/// spoilers, as no more common tag hides text. Headings are bold, inline code
+/// keeps its backticks, tables become rows of cells separated by `|`, and raw
+/// HTML is dropped. BBCode has no escaping, so text that looks like a tag gets
+/// a zero-width space after its `[`.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{bbcode, Parser};
+///
+/// let markdown_str = r#"
+/// Read *the* [manual](https://example.com/manual):
+///
+/// * alpha
+/// * beta
+/// "#;
+///
+/// let mut bbcode_buf = String::new();
+/// bbcode::push_bbcode(&mut bbcode_buf, Parser::new(markdown_str));
+///
+/// assert_eq!(bbcode_buf, "Read [i]the[/i] [url=https://example.com/manual]manual[/url]:
+///
+/// [list]
+/// [*]alpha
+/// [*]beta
+/// [/list]");
+/// ```
+pub fn push_bbcode<'a, I: Iterator<Item=Event<'a>>>(buf: &mut String, iter: I) {
+    let start = buf.len();
+    let mut ctx = Ctx {
+        iter: iter,
+        buf: buf,
+        links: Vec::new(),
+        lists: 0,
+        block_start: start,
+        first_cell: true,
+        cell_start: 0,
+        numbers: FootnoteNumbers::new(),
+    };
+    ctx.run();
+}
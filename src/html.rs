@@ -18,57 +18,31 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE.
 
-// This is a copy of pulldown_cmark's html.rs, modified to work in GTK.
-
 //! HTML renderer that takes an iterator of events as input.
 
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt::Write;
 
 use escape::{escape_html, escape_href};
-use parse::Event::{Start, End, Text, Html, InlineHtml, SoftBreak, HardBreak, FootnoteReference};
-use parse::{Event, Tag};
-use linkify::{LinkFinder, LinkKind};
+use render;
+use parse::Event::{Start, End, Text, Html, InlineHtml, SoftBreak, HardBreak, FootnoteReference,
+    TaskListMarker, Mention, Emoji};
+use parse::{Event, Tag, MentionKind};
 
 struct Ctx<'b, I> {
     iter: I,
-    buf: &'b mut String
+    buf: &'b mut String,
 }
 
 impl<'a, 'b, I: Iterator<Item=Event<'a>>> Ctx<'b, I> {
-    fn fresh_line(&mut self) {
-        if !(self.buf.is_empty() || self.buf.ends_with('\n')) {
-            self.buf.push('\n');
-        }
-    }
-
     pub fn run(&mut self) {
         let mut numbers = HashMap::new();
         while let Some(event) = self.iter.next() {
             match event {
                 Start(tag) => self.start_tag(tag, &mut numbers),
                 End(tag) => self.end_tag(tag),
-                Text(text) => {
-                    let mut finder = LinkFinder::new();
-                    finder.kinds(&[LinkKind::Url]);
-                    let mut appended = 0;
-
-                    let mut escaped = String::with_capacity(text.len());
-                    escape_html(&mut escaped, &text, false);
-
-                    for link in finder.links(&escaped.clone()) {
-                        let mut string = String::with_capacity(9 + (link.end() - link.start()) + 2);
-                        string.push_str("<a href=\"");
-                        escape_href(&mut string, link.as_str());
-                        string.push_str("\">");
-
-                        escaped.reserve(string.len() + 4);
-                        escaped.insert_str(appended + link.end(), "</a>");
-                        escaped.insert_str(appended + link.start(), &string);
-                        appended += string.len() + 4;
-                    }
-                    self.buf.push_str(&escaped);
-                }
+                Text(text) => escape_html(self.buf, &text, false),
                 Html(html) |
                 InlineHtml(html) => self.buf.push_str(&html),
                 SoftBreak => self.buf.push('\n'),
@@ -79,26 +53,96 @@ impl<'a, 'b, I: Iterator<Item=Event<'a>>> Ctx<'b, I> {
                     escape_html(self.buf, &*name, false);
                     self.buf.push_str("\">");
                     let number = numbers.entry(name).or_insert(len);
-                    self.buf.push_str(&*format!("{}", number));
+                    let _ = write!(self.buf, "{}", number);
                     self.buf.push_str("</a></sup>");
                 },
+                TaskListMarker(checked) => {
+                    self.buf.push_str("<input disabled=\"\" type=\"checkbox\"");
+                    if checked {
+                        self.buf.push_str(" checked=\"\"");
+                    }
+                    self.buf.push_str(" />\n");
+                }
+                Mention(kind, name) => {
+                    self.buf.push_str(match kind {
+                        MentionKind::User => "<span class=\"user-mention\">@",
+                        MentionKind::Channel => "<span class=\"channel-mention\">#",
+                    });
+                    escape_html(self.buf, &name, false);
+                    self.buf.push_str("</span>");
+                }
+                Emoji(_, emoji) => escape_html(self.buf, &emoji, false),
             }
         }
     }
 
-    fn start_tag(&mut self, tag: Tag<'a>, _numbers: &mut HashMap<Cow<'a, str>, usize>) {
+    fn start_tag(&mut self, tag: Tag<'a>, numbers: &mut HashMap<Cow<'a, str>, usize>) {
         match tag {
-            Tag::Header(_) => {
-                self.fresh_line();
-                self.buf.push_str("<big>");
+            Tag::Paragraph => {
+                render::fresh_line(self.buf);
+                self.buf.push_str("<p>");
+            }
+            Tag::Rule => {
+                render::fresh_line(self.buf);
+                self.buf.push_str("<hr />\n")
+            }
+            Tag::Header(level) => {
+                render::fresh_line(self.buf);
+                let _ = write!(self.buf, "<h{}>", level);
+            }
+            Tag::Table(_) => {
+                render::fresh_line(self.buf);
+                self.buf.push_str("<table>");
+            }
+            Tag::TableHead => self.buf.push_str("<thead><tr>"),
+            Tag::TableRow => self.buf.push_str("<tr>"),
+            Tag::TableCell => self.buf.push_str("<td>"),
+            Tag::BlockQuote => {
+                render::fresh_line(self.buf);
+                self.buf.push_str("<blockquote>\n");
+            }
+            Tag::CodeBlock(info) => {
+                render::fresh_line(self.buf);
+                let lang = info.split(' ').next().unwrap_or("");
+                if lang.is_empty() {
+                    self.buf.push_str("<pre><code>");
+                } else {
+                    self.buf.push_str("<pre><code class=\"language-");
+                    escape_html(self.buf, lang, false);
+                    self.buf.push_str("\">");
+                }
+            }
+            Tag::List(Some(1)) => {
+                render::fresh_line(self.buf);
+                self.buf.push_str("<ol>\n");
+            }
+            Tag::List(Some(start)) => {
+                render::fresh_line(self.buf);
+                let _ = write!(self.buf, "<ol start=\"{}\">\n", start);
+            }
+            Tag::List(None) => {
+                render::fresh_line(self.buf);
+                self.buf.push_str("<ul>\n");
             }
-            Tag::CodeBlock(_) => {
-                self.fresh_line();
-                self.buf.push_str("<tt>");
+            Tag::Item => {
+                render::fresh_line(self.buf);
+                self.buf.push_str("<li>");
             }
-            Tag::Emphasis => self.buf.push_str("<i>"),
-            Tag::Strong => self.buf.push_str("<b>"),
-            Tag::Code => self.buf.push_str("<tt>"),
+            Tag::FootnoteDefinition(name) => {
+                render::fresh_line(self.buf);
+                let len = numbers.len() + 1;
+                self.buf.push_str("<div class=\"footnote-definition\" id=\"");
+                escape_html(self.buf, &*name, false);
+                self.buf.push_str("\"><sup class=\"footnote-definition-label\">");
+                let number = numbers.entry(name).or_insert(len);
+                let _ = write!(self.buf, "{}", number);
+                self.buf.push_str("</sup>");
+            }
+            Tag::Emphasis => self.buf.push_str("<em>"),
+            Tag::Strong => self.buf.push_str("<strong>"),
+            Tag::Strikethrough => self.buf.push_str("<del>"),
+            Tag::Spoiler => self.buf.push_str("<span class=\"spoiler\">"),
+            Tag::Code => self.buf.push_str("<code>"),
             Tag::Link(dest, title) => {
                 self.buf.push_str("<a href=\"");
                 escape_href(self.buf, &dest);
@@ -108,19 +152,77 @@ impl<'a, 'b, I: Iterator<Item=Event<'a>>> Ctx<'b, I> {
                 }
                 self.buf.push_str("\">");
             }
-            _ => ()
+            Tag::Image(dest, title) => {
+                self.buf.push_str("<img src=\"");
+                escape_href(self.buf, &dest);
+                self.buf.push_str("\" alt=\"");
+                self.raw_text(numbers);
+                if !title.is_empty() {
+                    self.buf.push_str("\" title=\"");
+                    escape_html(self.buf, &title, false);
+                }
+                self.buf.push_str("\" />")
+            }
         }
     }
 
     fn end_tag(&mut self, tag: Tag) {
         match tag {
-            Tag::Header(_) => self.buf.push_str("</big>"),
-            Tag::CodeBlock(_) => self.buf.push_str("</tt>\n"),
-            Tag::Emphasis => self.buf.push_str("</i>"),
-            Tag::Strong => self.buf.push_str("</b>"),
-            Tag::Code => self.buf.push_str("</tt>"),
+            Tag::Paragraph => self.buf.push_str("</p>\n"),
+            Tag::Rule => (),
+            Tag::Header(level) => {
+                let _ = write!(self.buf, "</h{}>\n", level);
+            }
+            Tag::Table(_) => self.buf.push_str("</table>\n"),
+            Tag::TableHead => self.buf.push_str("</tr></thead>\n"),
+            Tag::TableRow => self.buf.push_str("</tr>\n"),
+            Tag::TableCell => self.buf.push_str("</td>"),
+            Tag::BlockQuote => self.buf.push_str("</blockquote>\n"),
+            Tag::CodeBlock(_) => self.buf.push_str("</code></pre>\n"),
+            Tag::List(Some(_)) => self.buf.push_str("</ol>\n"),
+            Tag::List(None) => self.buf.push_str("</ul>\n"),
+            Tag::Item => self.buf.push_str("</li>\n"),
+            Tag::FootnoteDefinition(_) => self.buf.push_str("</div>\n"),
+            Tag::Emphasis => self.buf.push_str("</em>"),
+            Tag::Strong => self.buf.push_str("</strong>"),
+            Tag::Strikethrough => self.buf.push_str("</del>"),
+            Tag::Spoiler => self.buf.push_str("</span>"),
+            Tag::Code => self.buf.push_str("</code>"),
             Tag::Link(_, _) => self.buf.push_str("</a>"),
-            _ => ()
+            // the end of an image is consumed along with its alt text
+            Tag::Image(_, _) => (),
+        }
+    }
+
+    // Write the text of the events up to the end of the current tag, without
+    // markup, consuming the end tag too.
+    fn raw_text(&mut self, numbers: &mut HashMap<Cow<'a, str>, usize>) {
+        let mut nest = 0;
+        while let Some(event) = self.iter.next() {
+            match event {
+                Start(_) => nest += 1,
+                End(_) => {
+                    if nest == 0 {
+                        break;
+                    }
+                    nest -= 1;
+                }
+                Text(text) => escape_html(self.buf, &text, false),
+                Html(_) => (),
+                InlineHtml(html) => escape_html(self.buf, &html, false),
+                SoftBreak | HardBreak => self.buf.push(' '),
+                TaskListMarker(_) => (),
+                Emoji(_, emoji) => escape_html(self.buf, &emoji, false),
+                Mention(kind, name) => {
+                    self.buf.push(kind.sigil());
+                    escape_html(self.buf, &name, false);
+                }
+                FootnoteReference(name) => {
+                    let len = numbers.len() + 1;
+                    let number = numbers.entry(name).or_insert(len);
+                    let _ = write!(self.buf, "[{}]", number);
+                }
+            }
         }
     }
 }
@@ -128,6 +230,10 @@ impl<'a, 'b, I: Iterator<Item=Event<'a>>> Ctx<'b, I> {
 /// Iterate over an `Iterator` of `Event`s, generate HTML for each `Event`, and
 /// push it to a `String`.
 ///
+/// The output follows the CommonMark reference implementation, with raw HTML
+/// passed through untouched. For markup that GTK labels can show, see the
+/// `pango` module instead.
+///
 /// # Examples
 ///
 /// ```
@@ -155,7 +261,7 @@ impl<'a, 'b, I: Iterator<Item=Event<'a>>> Ctx<'b, I> {
 pub fn push_html<'a, I: Iterator<Item=Event<'a>>>(buf: &mut String, iter: I) {
     let mut ctx = Ctx {
         iter: iter,
-        buf: buf
+        buf: buf,
     };
     ctx.run();
 }
@@ -0,0 +1,345 @@
+// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! IRC renderer that takes an iterator of events as input, using the mIRC
+//! formatting codes most clients understand.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use render::{self, BULLETS, FootnoteNumbers, is_printable};
+use parse::Event::{Start, End, Text, Html, InlineHtml, SoftBreak, HardBreak, FootnoteReference,
+    TaskListMarker, Mention, Emoji};
+use parse::{Event, Tag};
+
+// Formatting codes, each toggling its format on and off.
+const BOLD: char = '\x02';
+const ITALIC: char = '\x1d';
+const MONOSPACE: char = '\x11';
+const STRIKETHROUGH: char = '\x1e';
+const COLOR: char = '\x03';
+const RESET: char = '\x0f';
+// the mIRC color used for quote markers and rules
+const GREY: &'static str = "14";
+// black on black, which clients show as a spoiler until it's selected
+const SPOILER: &'static str = "01,01";
+
+struct Ctx<'a, 'b, I> {
+    iter: I,
+    buf: &'b mut String,
+    // the formatting codes in effect, with how many spans asked for each
+    formats: Vec<(char, usize)>,
+    // whether the current line still needs its quote markers and codes
+    line_start: bool,
+    quotes: usize,
+    // number of open spoilers
+    spoilers: usize,
+    // next item number for each open list, `None` for bullet lists
+    lists: Vec<Option<usize>>,
+    // buffer offset where the text of each open link begins, and its destination
+    links: Vec<(usize, Cow<'a, str>)>,
+    // buffer offset at which a block needs no line break in front of it
+    block_start: usize,
+    first_cell: bool,
+    cell_start: usize,
+    numbers: FootnoteNumbers<'a>,
+}
+
+impl<'a, 'b, I: Iterator<Item=Event<'a>>> Ctx<'a, 'b, I> {
+    // Clients reset formatting at the end of each message, so every line
+    // starts its quote markers and formatting afresh.
+    fn line_prefix(&mut self) {
+        if !self.line_start {
+            return;
+        }
+        self.line_start = false;
+        for _ in 0..self.quotes {
+            let _ = write!(self.buf, "{}{}>{} ", COLOR, GREY, COLOR);
+        }
+        for &(code, count) in &self.formats {
+            if count > 0 {
+                self.buf.push(code);
+            }
+        }
+        if self.spoilers > 0 {
+            let _ = write!(self.buf, "{}{}", COLOR, SPOILER);
+        }
+    }
+
+    fn newline(&mut self) {
+        let formatted = self.spoilers > 0 || self.formats.iter().any(|&(_, count)| count > 0);
+        if formatted && !self.line_start {
+            self.buf.push(RESET);
+        }
+        self.buf.push('\n');
+        self.line_start = true;
+    }
+
+    fn fresh_line(&mut self) {
+        if !self.buf.is_empty() && !self.line_start && self.buf.len() != self.block_start {
+            self.newline();
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.newline();
+            }
+            if line.is_empty() {
+                continue;
+            }
+            self.line_prefix();
+            // a digit right after a bare color code would be read as a color,
+            // so turn bold on and off in between
+            if self.buf.ends_with(COLOR) && line.starts_with(|c: char| c.is_ascii_digit()) {
+                self.buf.push(BOLD);
+                self.buf.push(BOLD);
+            }
+            // control characters in the source would be taken for formatting
+            self.buf.extend(line.chars().filter(|&c| is_printable(c)));
+        }
+    }
+
+    fn start_format(&mut self, code: char) {
+        self.line_prefix();
+        let count = match self.formats.iter().position(|&(c, _)| c == code) {
+            Some(i) => {
+                self.formats[i].1 += 1;
+                self.formats[i].1
+            }
+            None => {
+                self.formats.push((code, 1));
+                1
+            }
+        };
+        // nested spans of the same format would toggle it off
+        if count == 1 {
+            self.buf.push(code);
+        }
+    }
+
+    fn end_format(&mut self, code: char) {
+        if let Some(i) = self.formats.iter().position(|&(c, _)| c == code) {
+            self.formats[i].1 -= 1;
+            if self.formats[i].1 == 0 && !self.line_start {
+                self.buf.push(code);
+            }
+        }
+    }
+
+    pub fn run(&mut self) {
+        let start = self.buf.len();
+        while let Some(event) = self.iter.next() {
+            match event {
+                Start(tag) => self.start_tag(tag),
+                End(tag) => self.end_tag(tag),
+                Text(text) => self.text(&text),
+                // raw HTML can't be shown on IRC
+                Html(_) | InlineHtml(_) => (),
+                // lines become separate messages, so only hard breaks are kept
+                SoftBreak => self.text(" "),
+                HardBreak => self.newline(),
+                FootnoteReference(name) => {
+                    let number = render::footnote_number(&mut self.numbers, name);
+                    self.text(&format!("[{}]", number));
+                }
+                TaskListMarker(checked) => {
+                    self.text(if checked { "☑ " } else { "☐ " });
+                    self.block_start = self.buf.len();
+                }
+                // clients highlight nicks and link channels themselves
+                Mention(kind, name) => self.text(&format!("{}{}", kind.sigil(), name)),
+                Emoji(_, emoji) => self.text(&emoji),
+            }
+        }
+        render::trim_end_from(self.buf, start);
+    }
+
+    fn start_tag(&mut self, tag: Tag<'a>) {
+        match tag {
+            Tag::Paragraph | Tag::Table(_) => self.fresh_line(),
+            Tag::Header(_) => {
+                self.fresh_line();
+                self.start_format(BOLD);
+            }
+            Tag::Rule => {
+                self.fresh_line();
+                self.line_prefix();
+                let _ = write!(self.buf, "{}{}", COLOR, GREY);
+                for _ in 0..20 {
+                    self.buf.push('─');
+                }
+                self.buf.push(COLOR);
+                self.newline();
+            }
+            Tag::CodeBlock(_) => {
+                self.fresh_line();
+                self.start_format(MONOSPACE);
+            }
+            Tag::BlockQuote => {
+                self.fresh_line();
+                self.quotes += 1;
+            }
+            Tag::List(start) => {
+                self.fresh_line();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.fresh_line();
+                let depth = self.lists.len();
+                let mut marker = String::new();
+                for _ in 1..depth {
+                    marker.push_str("  ");
+                }
+                match self.lists.last_mut() {
+                    Some(&mut Some(ref mut number)) => {
+                        let _ = write!(marker, "{}. ", number);
+                        *number += 1;
+                    }
+                    _ => {
+                        marker.push_str(BULLETS[depth.saturating_sub(1) % BULLETS.len()]);
+                        marker.push(' ');
+                    }
+                }
+                self.text(&marker);
+                self.block_start = self.buf.len();
+            }
+            Tag::TableHead => {
+                self.fresh_line();
+                self.start_format(BOLD);
+                self.first_cell = true;
+            }
+            Tag::TableRow => {
+                self.fresh_line();
+                self.first_cell = true;
+            }
+            Tag::TableCell => {
+                if !self.first_cell {
+                    self.text(" | ");
+                }
+                self.first_cell = false;
+                self.line_prefix();
+                self.cell_start = self.buf.len();
+            }
+            Tag::FootnoteDefinition(name) => {
+                self.fresh_line();
+                let number = render::footnote_number(&mut self.numbers, name);
+                self.text(&format!("[{}] ", number));
+                self.block_start = self.buf.len();
+            }
+            Tag::Emphasis => self.start_format(ITALIC),
+            Tag::Strong => self.start_format(BOLD),
+            Tag::Strikethrough => self.start_format(STRIKETHROUGH),
+            Tag::Spoiler => {
+                self.line_prefix();
+                self.spoilers += 1;
+                if self.spoilers == 1 {
+                    let _ = write!(self.buf, "{}{}", COLOR, SPOILER);
+                }
+            }
+            Tag::Code => self.start_format(MONOSPACE),
+            Tag::Link(dest, _) | Tag::Image(dest, _) => {
+                self.line_prefix();
+                let start = self.buf.len();
+                self.links.push((start, dest));
+            }
+        }
+    }
+
+    fn end_tag(&mut self, tag: Tag) {
+        match tag {
+            Tag::Header(_) | Tag::TableHead => self.end_format(BOLD),
+            Tag::CodeBlock(_) | Tag::Code => self.end_format(MONOSPACE),
+            Tag::BlockQuote => {
+                self.fresh_line();
+                self.quotes -= 1;
+            }
+            Tag::TableCell => {
+                let cell = self.buf.split_off(self.cell_start);
+                self.buf.push_str(cell.trim());
+            }
+            Tag::List(_) => {
+                self.lists.pop();
+                self.fresh_line();
+            }
+            Tag::Emphasis => self.end_format(ITALIC),
+            Tag::Strong => self.end_format(BOLD),
+            Tag::Strikethrough => self.end_format(STRIKETHROUGH),
+            Tag::Spoiler => {
+                self.spoilers -= 1;
+                if self.spoilers == 0 && !self.line_start {
+                    self.buf.push(COLOR);
+                }
+            }
+            // clients turn URLs into links themselves, so the destination
+            // follows the text unless it is the text
+            Tag::Link(_, _) | Tag::Image(_, _) => {
+                if let Some((start, dest)) = self.links.pop() {
+                    let text: String = self.buf[start..].chars().filter(|&c| is_printable(c)).collect();
+                    let is_dest = text == dest ||
+                        (dest.starts_with("mailto:") && text == &dest[7..]);
+                    if text.is_empty() {
+                        self.text(&dest);
+                    } else if !is_dest {
+                        self.text(&format!(" ({})", dest));
+                    }
+                }
+            }
+            _ => ()
+        }
+    }
+}
+
+/// Iterate over an `Iterator` of `Event`s, and push text with IRC formatting
+/// codes for each `Event` to a `String`.
+///
+/// Each line of the output is meant to be sent as a message of its own, so
+/// formatting is reset at the end of every line and restarted on the next.
+/// Soft breaks become spaces, links are followed by their destination in
+/// parentheses, and raw HTML is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{irc, Parser};
+///
+/// let mut irc_buf = String::new();
+/// irc::push_irc(&mut irc_buf, Parser::new("Some **bold** and `code`"));
+///
+/// assert_eq!(irc_buf, "Some \x02bold\x02 and \x11code\x11");
+/// ```
+pub fn push_irc<'a, I: Iterator<Item=Event<'a>>>(buf: &mut String, iter: I) {
+    let mut ctx = Ctx {
+        iter: iter,
+        buf: buf,
+        formats: Vec::new(),
+        line_start: true,
+        quotes: 0,
+        spoilers: 0,
+        lists: Vec::new(),
+        links: Vec::new(),
+        block_start: 0,
+        first_cell: true,
+        cell_start: 0,
+        numbers: FootnoteNumbers::new(),
+    };
+    ctx.run();
+}
@@ -25,19 +25,39 @@
 #![cfg_attr(rustbuild, feature(staged_api, rustc_private))]
 #![cfg_attr(rustbuild, unstable(feature = "rustc_private", issue = "27812"))]
 
+pub mod ansi;
+pub mod bbcode;
+pub mod emoji;
 pub mod html;
+pub mod irc;
+pub mod markdown;
+pub mod matrix;
+pub mod pango;
+pub mod styled;
+pub mod text;
+pub mod xml;
 
 #[macro_use]
 extern crate bitflags;
 extern crate linkify;
+extern crate unicode_width;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
 
 mod passes;
 mod parse;
 mod scanners;
 mod entities;
 mod escape;
+mod links;
+mod render;
 mod puncttable;
+mod sanitize;
 mod utils;
 
 pub use passes::Parser;
-pub use parse::{Alignment, Event, Tag, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES};
+pub use parse::{Alignment, Event, Tag, MentionKind, Options, OPTION_ENABLE_TABLES,
+    OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_AUTOLINK, OPTION_ENABLE_STRIKETHROUGH,
+    OPTION_ENABLE_TASKLISTS, OPTION_ENABLE_MENTIONS, OPTION_ENABLE_EMOJI,
+    OPTION_ENABLE_SPOILERS};
@@ -0,0 +1,68 @@
+// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Finding the bare URLs and email addresses that the autolink pass links.
+
+use linkify::{LinkFinder, LinkKind};
+
+// The URLs and email addresses in `text` that get autolinked, as their start
+// and end offsets and whether they're an email address.
+pub fn find_links(text: &str) -> Vec<(usize, usize, bool)> {
+    let mut finder = LinkFinder::new();
+    finder.kinds(&[LinkKind::Url, LinkKind::Email]);
+    finder.links(text).map(|link| {
+        (link.start(), link.end(), *link.kind() == LinkKind::Email)
+    }).collect()
+}
+
+// The links in the run of text between two whitespace characters, kept
+// around so that looking up every `@` or `:` in a long run finds them once.
+pub struct BareLinks {
+    start: usize,
+    end: usize,
+    ranges: Vec<(usize, usize)>,
+}
+
+impl BareLinks {
+    pub fn new() -> BareLinks {
+        BareLinks {
+            start: 0,
+            end: 0,
+            ranges: Vec::new(),
+        }
+    }
+
+    // Whether `loc` falls inside a URL or email address in `text` that would
+    // be autolinked.
+    pub fn contains(&mut self, text: &str, loc: usize) -> bool {
+        if loc < self.start || loc >= self.end {
+            self.start = match text[..loc].char_indices().rev().find(|&(_, c)| c.is_whitespace()) {
+                Some((i, c)) => i + c.len_utf8(),
+                None => 0
+            };
+            self.end = text[loc..].find(char::is_whitespace).map_or(text.len(), |i| loc + i);
+            let start = self.start;
+            self.ranges = find_links(&text[start..self.end]).into_iter()
+                .map(|(link_start, link_end, _)| (start + link_start, start + link_end))
+                .collect();
+        }
+        self.ranges.iter().any(|&(start, end)| start <= loc && loc < end)
+    }
+}
@@ -23,10 +23,16 @@
 extern crate getopts;
 
 extern crate pulldown_cmark;
+#[cfg(feature = "json")]
+extern crate serde_json;
 
 use pulldown_cmark::Parser;
-use pulldown_cmark::{Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES};
-use pulldown_cmark::html;
+use pulldown_cmark::{Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_AUTOLINK,
+    OPTION_ENABLE_STRIKETHROUGH, OPTION_ENABLE_TASKLISTS, OPTION_ENABLE_MENTIONS, OPTION_ENABLE_EMOJI,
+    OPTION_ENABLE_SPOILERS};
+use pulldown_cmark::{ansi, bbcode, html, irc, markdown, matrix, pango, text, xml};
+use pulldown_cmark::ansi::AnsiOptions;
+use pulldown_cmark::markdown::{HeadingStyle, LinkStyle, MarkdownOptions};
 
 use std::env;
 use std::io;
@@ -41,6 +47,47 @@ fn render_html(text: &str, opts: Options) -> String {
     s
 }
 
+// Output formats for the rendered document.
+enum Format {
+    Html,
+    Pango,
+    Text,
+    Xml,
+    Json,
+    Irc,
+    Bbcode,
+    Matrix,
+    Ansi(AnsiOptions),
+    Markdown(MarkdownOptions),
+}
+
+fn render(text: &str, opts: Options, format: &Format) -> String {
+    let mut s = String::with_capacity(text.len() * 3 / 2);
+    let p = Parser::new_ext(text, opts);
+    match *format {
+        Format::Html => html::push_html(&mut s, p),
+        Format::Pango => pango::push_html(&mut s, p),
+        Format::Text => {
+            text::push_text(&mut s, p);
+            s.push('\n');
+        }
+        Format::Xml => xml::push_xml(&mut s, p),
+        Format::Json => push_json_events(&mut s, p),
+        Format::Irc => {
+            irc::push_irc(&mut s, p);
+            s.push('\n');
+        }
+        Format::Matrix => matrix::push_matrix(&mut s, p),
+        Format::Bbcode => {
+            bbcode::push_bbcode(&mut s, p);
+            s.push('\n');
+        }
+        Format::Ansi(ref ansi_opts) => ansi::push_ansi_with(&mut s, p, ansi_opts),
+        Format::Markdown(ref markdown_opts) => markdown::push_markdown_with(&mut s, p, markdown_opts),
+    }
+    s
+}
+
 fn dry_run(text:&str, opts: Options) {
     let p = Parser::new_ext(text, opts);
     /*
@@ -64,6 +111,28 @@ fn print_events(text: &str, opts: Options) {
     println!("EOF");
 }
 
+// One JSON object per line, with the event and the source offset it was
+// parsed from, for tools that need the structure.
+#[cfg(feature = "json")]
+fn push_json_events(buf: &mut String, mut p: Parser) {
+    loop {
+        let offset = p.get_offset();
+        match p.next() {
+            Some(event) => {
+                let event = serde_json::to_string(&event).expect("events always serialize");
+                buf.push_str(&format!("{{\"offset\":{},\"event\":{}}}\n", offset, event));
+            }
+            None => break,
+        }
+    }
+}
+
+#[cfg(not(feature = "json"))]
+fn push_json_events(_buf: &mut String, _p: Parser) {
+    let _ = writeln!(io::stderr(), "json output needs the json feature");
+    std::process::exit(1);
+}
+
 fn read_file(filename: &str) -> String {
     let path = Path::new(filename);
     let mut file = match File::open(&path) {
@@ -198,6 +267,40 @@ fn brief<ProgramName>(program: ProgramName) -> String
     return format!("Usage: {} FILE [options]", program);
 }
 
+fn markdown_options(matches: &getopts::Matches, width: Option<usize>) -> MarkdownOptions {
+    let mut markdown_opts = MarkdownOptions { wrap: width, ..MarkdownOptions::default() };
+    match matches.opt_str("emphasis").as_ref().map(|c| &c[..]) {
+        None | Some("*") => (),
+        Some("_") => markdown_opts.emphasis = '_',
+        Some(other) => {
+            let _ = writeln!(io::stderr(), "invalid emphasis character: {}", other);
+            std::process::exit(1);
+        }
+    }
+    match matches.opt_str("bullet").as_ref().map(|c| &c[..]) {
+        None | Some("-") => (),
+        Some("*") => markdown_opts.bullet = '*',
+        Some("+") => markdown_opts.bullet = '+',
+        Some(other) => {
+            let _ = writeln!(io::stderr(), "invalid bullet: {}", other);
+            std::process::exit(1);
+        }
+    }
+    if matches.opt_present("setext") {
+        markdown_opts.headings = HeadingStyle::Setext;
+    }
+    markdown_opts.links = match matches.opt_str("links").as_ref().map(|l| &l[..]) {
+        None | Some("inline") => LinkStyle::Inline,
+        Some("block") => LinkStyle::AfterBlock,
+        Some("end") => LinkStyle::AtEnd,
+        Some(other) => {
+            let _ = writeln!(io::stderr(), "unknown link style: {}", other);
+            std::process::exit(1);
+        }
+    };
+    markdown_opts
+}
+
 pub fn main() {
     let args: Vec<_> = env::args().collect();
     let mut opts = getopts::Options::new();
@@ -206,6 +309,19 @@ pub fn main() {
     opts.optflag("e", "events", "print event sequence instead of rendering");
     opts.optflag("T", "enable-tables", "enable GitHub-style tables");
     opts.optflag("F", "enable-footnotes", "enable Hoedown-style footnotes");
+    opts.optflag("L", "enable-autolink", "turn bare URLs and email addresses into links");
+    opts.optflag("S", "enable-strikethrough", "enable GitHub-style ~~strikethrough~~");
+    opts.optflag("K", "enable-tasklists", "enable GitHub-style [ ] task list items");
+    opts.optflag("M", "enable-mentions", "enable @user and #channel mentions");
+    opts.optflag("E", "enable-emoji", "enable :shortcode: emoji");
+    opts.optflag("P", "enable-spoilers", "enable Discord-style ||spoilers||");
+    opts.optopt("f", "format", "output format: html (default), pango, text, ansi, irc, bbcode, matrix, xml or json", "FORMAT");
+    opts.optopt("w", "width", "column to wrap ansi or --fmt output at", "COLUMNS");
+    opts.optflag("", "fmt", "rewrite the input as canonical Markdown");
+    opts.optopt("", "emphasis", "emphasis character for --fmt: * (default) or _", "CHAR");
+    opts.optopt("", "bullet", "list bullet for --fmt: - (default), * or +", "CHAR");
+    opts.optflag("", "setext", "write level 1 and 2 headings as setext with --fmt");
+    opts.optopt("", "links", "link style for --fmt: inline (default), block or end", "STYLE");
     opts.optopt("s", "spec", "run tests from spec file", "FILE");
     opts.optopt("b", "bench", "run benchmark", "FILE");
     let matches = match opts.parse(&args[1..]) {
@@ -235,6 +351,53 @@ pub fn main() {
     if matches.opt_present("enable-footnotes") {
         opts.insert(OPTION_ENABLE_FOOTNOTES);
     }
+    if matches.opt_present("enable-autolink") {
+        opts.insert(OPTION_ENABLE_AUTOLINK);
+    }
+    if matches.opt_present("enable-strikethrough") {
+        opts.insert(OPTION_ENABLE_STRIKETHROUGH);
+    }
+    if matches.opt_present("enable-tasklists") {
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+    }
+    if matches.opt_present("enable-mentions") {
+        opts.insert(OPTION_ENABLE_MENTIONS);
+    }
+    if matches.opt_present("enable-emoji") {
+        opts.insert(OPTION_ENABLE_EMOJI);
+    }
+    if matches.opt_present("enable-spoilers") {
+        opts.insert(OPTION_ENABLE_SPOILERS);
+    }
+    let width = match matches.opt_str("width") {
+        Some(width) => match width.parse() {
+            Ok(width) => Some(width),
+            Err(_) => {
+                let _ = writeln!(io::stderr(), "invalid width: {}", width);
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+    let format = if matches.opt_present("fmt") {
+        Format::Markdown(markdown_options(&matches, width))
+    } else {
+        match matches.opt_str("format").as_ref().map(|f| &f[..]) {
+            None | Some("html") => Format::Html,
+            Some("pango") => Format::Pango,
+            Some("text") => Format::Text,
+            Some("xml") => Format::Xml,
+            Some("json") => Format::Json,
+            Some("irc") => Format::Irc,
+            Some("bbcode") => Format::Bbcode,
+            Some("matrix") => Format::Matrix,
+            Some("ansi") => Format::Ansi(AnsiOptions { width: width, ..AnsiOptions::default() }),
+            Some(other) => {
+                let _ = writeln!(io::stderr(), "unknown format: {}", other);
+                std::process::exit(1);
+            }
+        }
+    };
     if let Some(filename) = matches.opt_str("spec") {
         run_spec(&read_file(&filename).replace("→", "\t"), &matches.free, opts);
     } else if let Some(filename) = matches.opt_str("bench") {
@@ -252,7 +415,7 @@ pub fn main() {
         } else if matches.opt_present("dry-run") {
             dry_run(&input, opts);
         } else {
-            print!("{}", render_html(&input, opts));
+            print!("{}", render(&input, opts, &format));
         }
     }
 }
@@ -0,0 +1,903 @@
+// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! CommonMark writer that turns an iterator of events back into Markdown source.
+
+use std::cmp;
+
+use parse::Event::{Start, End, Text, Html, InlineHtml, SoftBreak, HardBreak, FootnoteReference,
+    TaskListMarker, Mention, Emoji};
+use parse::{Alignment, Event, Tag};
+
+/// How headings of level 1 and 2 are written; deeper levels are always ATX.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HeadingStyle {
+    /// `# Heading`
+    Atx,
+    /// `Heading` underlined with `===` or `---`.
+    Setext,
+}
+
+/// How links and images are written.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LinkStyle {
+    /// `[text](destination "title")`
+    Inline,
+    /// `[text][1]`, with the definitions after the top-level block that uses them.
+    AfterBlock,
+    /// `[text][1]`, with the definitions at the end of the document.
+    AtEnd,
+}
+
+/// Options for `push_markdown_with`.
+#[derive(Clone, Debug)]
+pub struct MarkdownOptions {
+    /// Character for emphasis and strong emphasis, `*` or `_`. Emphasis in
+    /// the middle of a word always uses `*`.
+    pub emphasis: char,
+    /// Bullet for unordered lists: `-`, `*` or `+`.
+    pub bullet: char,
+    pub headings: HeadingStyle,
+    /// Column at which paragraphs are rewrapped, if any. Rewrapping turns
+    /// spaces into soft breaks and back, so the events no longer match
+    /// exactly, though the document renders the same.
+    pub wrap: Option<usize>,
+    pub links: LinkStyle,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> MarkdownOptions {
+        MarkdownOptions {
+            emphasis: '*',
+            bullet: '-',
+            headings: HeadingStyle::Atx,
+            wrap: None,
+            links: LinkStyle::Inline,
+        }
+    }
+}
+
+// A block that puts something in front of each of its lines.
+struct Container {
+    // written on the first line
+    marker: String,
+    // written on the lines after it
+    indent: String,
+    marker_pending: bool,
+    // whether blank lines may separate the blocks in it
+    loose: bool,
+}
+
+struct List {
+    // next item number, `None` for bullet lists
+    number: Option<usize>,
+    // bullet character, or delimiter after the number
+    delim: char,
+    loose: bool,
+}
+
+struct Ctx<'a, 'b, 'c> {
+    events: Vec<Event<'a>>,
+    buf: &'b mut String,
+    opts: &'c MarkdownOptions,
+    containers: Vec<Container>,
+    lists: Vec<List>,
+    // nothing has been written since the document or the innermost container started
+    block_start: bool,
+    // the prefixes of the current line have been written
+    line_open: bool,
+    // nothing but the prefixes has been written on the current line
+    line_start: bool,
+    // nothing but digits has been written on the current line
+    line_digits: bool,
+    in_header: bool,
+    in_table: bool,
+    // column alignments of the table being written
+    alignments: Vec<Alignment>,
+    // delimiter of the list that ended last, and whether it was loose
+    last_delim: char,
+    last_list_loose: bool,
+    // delimiters of the open emphasis and strong spans
+    emphasis: Vec<String>,
+    // nesting depth of the events written so far
+    depth: usize,
+    // buffer offset where the current line starts
+    line_begin: usize,
+    // offset of a space just written, which may become a line break
+    space_at: Option<usize>,
+    // offset of the last space on the current line that can become a line break
+    break_at: Option<usize>,
+    // level of the setext heading being written
+    setext: Option<i32>,
+    // number of links open around the current event
+    links: usize,
+    // destination and title of each link reference definition
+    definitions: Vec<(String, String)>,
+    // how many of the definitions have been written
+    written_definitions: usize,
+}
+
+// Length of the longest run of `c` in `s`.
+fn longest_run(s: &str, c: char) -> usize {
+    let mut longest = 0;
+    let mut run = 0;
+    for x in s.chars() {
+        if x == c {
+            run += 1;
+            longest = cmp::max(longest, run);
+        } else {
+            run = 0;
+        }
+    }
+    longest
+}
+
+fn push_repeated(buf: &mut String, c: char, count: usize) {
+    for _ in 0..count {
+        buf.push(c);
+    }
+}
+
+// Whether the list starting at `events[0]` has paragraphs in its items,
+// which only happens when the source had blank lines between its blocks.
+fn is_loose(events: &[Event]) -> bool {
+    let mut depth = 0;
+    for event in &events[1..] {
+        match *event {
+            Start(Tag::Paragraph) if depth == 1 => return true,
+            Start(_) => depth += 1,
+            End(_) if depth == 0 => break,
+            End(_) => depth -= 1,
+            _ => ()
+        }
+    }
+    false
+}
+
+// Index of the end event matching the start event at `events[0]`.
+fn matching_end(events: &[Event]) -> usize {
+    let mut depth = 0;
+    for (i, event) in events.iter().enumerate() {
+        match *event {
+            Start(_) => depth += 1,
+            End(_) => {
+                depth -= 1;
+                if depth == 0 {
+                    return i;
+                }
+            }
+            _ => ()
+        }
+    }
+    events.len()
+}
+
+fn is_emphasis(tag: &Tag) -> bool {
+    *tag == Tag::Emphasis || *tag == Tag::Strong
+}
+
+// Whether `s` starts with a URI scheme followed by a colon, as autolinks need.
+fn has_scheme(s: &str) -> bool {
+    let scheme = match s.find(':') {
+        Some(n) => &s[..n],
+        None => return false,
+    };
+    scheme.len() >= 2 && scheme.len() <= 32 &&
+        scheme.starts_with(|c: char| c.is_ascii_alphabetic()) &&
+        scheme.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '.' || c == '-')
+}
+
+fn push_escaped(buf: &mut String, s: &str, special: &[char]) {
+    for c in s.chars() {
+        if c == '\\' || special.contains(&c) {
+            buf.push('\\');
+        }
+        buf.push(c);
+    }
+}
+
+// Whether a line may start with `c` without it turning into block markup.
+fn can_start_line(c: char) -> bool {
+    match c {
+        '#' | '>' | '-' | '+' | '=' | '`' | '~' | '<' | '|' | ' ' => false,
+        c => !c.is_ascii_digit(),
+    }
+}
+
+impl<'a, 'b, 'c> Ctx<'a, 'b, 'c> {
+    fn start_line(&mut self) {
+        self.line_begin = self.buf.len();
+        self.space_at = None;
+        self.break_at = None;
+        for container in &mut self.containers {
+            if container.marker_pending {
+                self.buf.push_str(&container.marker);
+                container.marker_pending = false;
+            } else {
+                self.buf.push_str(&container.indent);
+            }
+        }
+        self.line_open = true;
+        self.line_start = true;
+        self.line_digits = false;
+        self.block_start = false;
+    }
+
+    fn ensure_line(&mut self) {
+        if !self.line_open {
+            self.start_line();
+        }
+    }
+
+    fn end_line(&mut self) {
+        if self.line_open {
+            self.buf.push('\n');
+            self.line_open = false;
+        }
+    }
+
+    // A blank line only gets the prefixes that keep its containers open.
+    fn blank_line(&mut self) {
+        let mut prefix = String::new();
+        for container in &self.containers {
+            prefix.push_str(&container.indent);
+        }
+        self.buf.push_str(prefix.trim_end());
+        self.buf.push('\n');
+    }
+
+    // Separate a new block from the one before it, with a blank line where
+    // that doesn't turn a tight list loose.
+    fn separate(&mut self, loose: bool) {
+        if !self.block_start {
+            self.end_line();
+            if loose {
+                self.blank_line();
+            }
+        }
+        self.block_start = false;
+    }
+
+    fn separate_block(&mut self) {
+        let loose = self.containers.last().map_or(true, |container| container.loose);
+        self.separate(loose);
+    }
+
+    fn push_container(&mut self, marker: String, indent: String, loose: bool) {
+        self.containers.push(Container {
+            marker: marker,
+            indent: indent,
+            marker_pending: true,
+            loose: loose,
+        });
+        self.block_start = true;
+    }
+
+    fn pop_container(&mut self) {
+        if self.containers.last().map_or(false, |container| container.marker_pending) {
+            // an empty container still needs its marker
+            self.ensure_line();
+            let len = self.buf.trim_end_matches(' ').len();
+            self.buf.truncate(len);
+        }
+        self.end_line();
+        self.containers.pop();
+        self.block_start = false;
+    }
+
+    fn text(&mut self, text: &str) {
+        self.ensure_line();
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            let line_start = self.line_start;
+            let after_digits = self.line_digits;
+            self.line_start = false;
+            self.line_digits = (line_start || after_digits) && c.is_ascii_digit();
+            // `@` or `#` starting a word could be taken for a mention, and the
+            // name may be in the next text event
+            let word_start = !self.buf.chars().next_back().map_or(false, |p| p.is_alphanumeric());
+            let mention = word_start &&
+                chars.peek().map_or(true, |&n| n.is_alphabetic() || n == '_');
+            // likewise `:` could start an emoji shortcode
+            let shortcode = word_start && chars.peek().map_or(true, |&n| {
+                n.is_ascii_alphanumeric() || n == '_' || n == '+' || n == '-'
+            });
+            let escape = match c {
+                '\\' | '`' | '*' | '_' | '~' | '[' | ']' | '<' => true,
+                // the entity may continue in the next text event
+                '&' => chars.peek().map_or(true, |&n| n.is_ascii_alphanumeric() || n == '#'),
+                // `||` could open a spoiler
+                '|' => self.in_table || self.buf.ends_with('|') ||
+                    chars.peek().map_or(true, |&n| n == '|'),
+                '#' => line_start || self.in_header || mention,
+                '@' => mention,
+                ':' => shortcode,
+                '>' | '-' | '+' | '=' => line_start,
+                // digits followed by `.` or `)` would start an ordered list
+                '.' | ')' => after_digits,
+                _ => false,
+            };
+            if escape {
+                self.before_char('\\');
+                self.buf.push('\\');
+            } else {
+                self.before_char(c);
+            }
+            if c == ' ' {
+                self.space();
+            } else {
+                self.buf.push(c);
+                self.wrap();
+            }
+        }
+    }
+
+    fn inline(&mut self, markup: &str) {
+        self.ensure_line();
+        if let Some(c) = markup.chars().next() {
+            self.before_char(c);
+        }
+        self.buf.push_str(markup);
+        self.line_start = false;
+        self.line_digits = false;
+        self.wrap();
+    }
+
+    // Write a space where the line may be wrapped.
+    fn space(&mut self) {
+        self.ensure_line();
+        self.line_start = false;
+        if self.opts.wrap.is_some() && !self.in_header && !self.in_table {
+            self.space_at = Some(self.buf.len());
+        }
+        self.buf.push(' ');
+    }
+
+    // A space is only a place to wrap if what follows it can start a line.
+    fn before_char(&mut self, c: char) {
+        if let Some(pos) = self.space_at.take() {
+            if can_start_line(c) {
+                self.break_at = Some(pos);
+            }
+        }
+    }
+
+    // Turn the last breakable space into a line break, if the line is too long.
+    fn wrap(&mut self) {
+        let width = match self.opts.wrap {
+            Some(width) => width,
+            None => return,
+        };
+        let pos = match self.break_at {
+            Some(pos) if self.buf[self.line_begin..].chars().count() > width => pos,
+            _ => return,
+        };
+        let rest = self.buf.split_off(pos);
+        // trailing spaces would make a hard break
+        let len = self.buf.trim_end_matches(|c| c == ' ' || c == '\t').len();
+        self.buf.truncate(len);
+        self.buf.push('\n');
+        self.line_begin = self.buf.len();
+        for container in &self.containers {
+            self.buf.push_str(&container.indent);
+        }
+        self.buf.push_str(&rest[1..]);
+        self.break_at = None;
+    }
+
+    // Write `text` as-is, line by line, inside the current containers.
+    fn raw_lines(&mut self, text: &str) {
+        let text = if text.ends_with('\n') { &text[..text.len() - 1] } else { text };
+        for line in text.split('\n') {
+            if line.is_empty() {
+                self.blank_line();
+            } else {
+                self.ensure_line();
+                self.buf.push_str(line);
+                self.end_line();
+            }
+        }
+    }
+
+    // Concatenate the text events from `events[start]` on, returning the
+    // text and the index of the first event after it.
+    fn collect_text(&self, start: usize) -> (String, usize) {
+        let mut text = String::new();
+        let mut i = start;
+        while let Some(&Text(ref more)) = self.events.get(i) {
+            text.push_str(more);
+            i += 1;
+        }
+        (text, i)
+    }
+
+    fn code_block(&mut self, info: &str, code: &str) {
+        self.separate_block();
+        // a fence must be longer than any run of its character in the code,
+        // and backtick fences can't have backticks in their info string
+        let fence_char = if info.contains('`') { '~' } else { '`' };
+        let fence_len = cmp::max(3, longest_run(code, fence_char) + 1);
+        let mut fence = String::new();
+        push_repeated(&mut fence, fence_char, fence_len);
+        self.ensure_line();
+        self.buf.push_str(&fence);
+        self.buf.push_str(info);
+        self.end_line();
+        if !code.is_empty() {
+            self.raw_lines(code);
+        }
+        self.ensure_line();
+        self.buf.push_str(&fence);
+        self.end_line();
+    }
+
+    fn code_span(&mut self, code: &str) {
+        if code.is_empty() {
+            self.inline("` `");
+            return;
+        }
+        let ticks = longest_run(code, '`') + 1;
+        let mut delim = String::new();
+        push_repeated(&mut delim, '`', ticks);
+        // leading and trailing whitespace is stripped, so only pad where
+        // a backtick would otherwise run into the delimiter
+        let pad = if code.starts_with('`') || code.ends_with('`') { " " } else { "" };
+        self.inline(&delim);
+        self.buf.push_str(pad);
+        self.buf.push_str(code);
+        self.buf.push_str(pad);
+        self.buf.push_str(&delim);
+    }
+
+    fn push_destination(&mut self, dest: &str, title: &str) {
+        push_escaped(self.buf, dest, &['(', ')', ' ', '<', '>']);
+        if !title.is_empty() {
+            self.buf.push_str(" \"");
+            push_escaped(self.buf, title, &['"']);
+            self.buf.push('"');
+        }
+    }
+
+    fn link_end(&mut self, dest: &str, title: &str) {
+        self.space_at = None;
+        // a reference needs a destination, and images in links stay inline
+        if self.opts.links == LinkStyle::Inline || dest.is_empty() || self.links > 0 {
+            self.buf.push_str("](");
+            self.push_destination(dest, title);
+            self.buf.push(')');
+            return;
+        }
+        let key = (dest.to_string(), title.to_string());
+        let label = match self.definitions.iter().position(|definition| *definition == key) {
+            Some(i) => i + 1,
+            None => {
+                self.definitions.push(key);
+                self.definitions.len()
+            }
+        };
+        self.buf.push_str(&format!("][{}]", label));
+        self.wrap();
+    }
+
+    // Write the link reference definitions that haven't been written yet.
+    fn write_definitions(&mut self) {
+        if self.written_definitions == self.definitions.len() {
+            return;
+        }
+        self.separate(true);
+        while self.written_definitions < self.definitions.len() {
+            let (dest, title) = self.definitions[self.written_definitions].clone();
+            self.written_definitions += 1;
+            self.ensure_line();
+            self.buf.push_str(&format!("[{}]: ", self.written_definitions));
+            self.push_destination(&dest, &title);
+            self.end_line();
+        }
+    }
+
+    // Write the link starting at `events[start - 1]` as an autolink, if its
+    // text is its destination. Returns the index of the event after it.
+    fn autolink(&mut self, start: usize, dest: &str, title: &str) -> Option<usize> {
+        let text = match (self.events.get(start), self.events.get(start + 1)) {
+            (Some(&Text(ref text)), Some(&End(_))) => text.clone(),
+            _ => return None,
+        };
+        let is_url = dest == text && has_scheme(dest);
+        let is_email = dest.starts_with("mailto:") && dest[7..] == *text && text.contains('@');
+        if !title.is_empty() || !(is_url || is_email) ||
+                text.contains(|c: char| c.is_whitespace() || c == '<' || c == '>') {
+            return None;
+        }
+        self.inline("<");
+        self.buf.push_str(&text);
+        self.buf.push('>');
+        Some(start + 2)
+    }
+
+    // Emphasis nested right inside other emphasis takes the other delimiter
+    // character, as runs of the same one would merge. Underscores don't work
+    // inside words, so there the runs are merged, the way `***a*b**` writes
+    // them.
+    fn emphasis_delim(&self, start: usize, strong: bool) -> String {
+        let end = start + matching_end(&self.events[start..]);
+        let after_start = match self.events.get(start.wrapping_sub(1)) {
+            Some(&Start(ref tag)) => is_emphasis(tag),
+            _ => false,
+        };
+        let before_end = match self.events.get(end + 1) {
+            Some(&End(ref tag)) => is_emphasis(tag),
+            _ => false,
+        };
+        // neighbouring emphasis counts as part of the word too
+        let in_word = self.buf.chars().next_back().map_or(false, |c| c.is_alphanumeric() || c == '_') ||
+            match self.events.get(end + 1) {
+                Some(&Text(ref text)) => text.starts_with(|c: char| c.is_alphanumeric()),
+                Some(&Start(ref tag)) => is_emphasis(tag),
+                _ => false,
+            };
+        let preferred = if self.opts.emphasis == '_' && !in_word { '_' } else { '*' };
+        let c = if (after_start || before_end) && !in_word {
+            match self.emphasis.last() {
+                Some(delim) if delim.starts_with('*') => '_',
+                _ => '*',
+            }
+        } else {
+            preferred
+        };
+        let mut delim = c.to_string();
+        if strong {
+            delim.push(c);
+        }
+        delim
+    }
+
+    fn run(&mut self) {
+        let mut i = 0;
+        while i < self.events.len() {
+            let event = self.events[i].clone();
+            i += 1;
+            match event {
+                Start(Tag::CodeBlock(info)) => {
+                    let (code, end) = self.collect_text(i);
+                    self.code_block(&info, &code);
+                    i = end + 1;
+                }
+                Start(Tag::Code) => {
+                    let (code, end) = self.collect_text(i);
+                    self.code_span(&code);
+                    i = end + 1;
+                }
+                Start(Tag::List(start)) => {
+                    // adjacent lists need different markers to stay apart
+                    let after_list = match self.events.get(i.wrapping_sub(2)) {
+                        Some(&End(Tag::List(_))) => true,
+                        _ => false,
+                    };
+                    let bullet = match self.opts.bullet {
+                        '*' => '*',
+                        '+' => '+',
+                        _ => '-',
+                    };
+                    let (delim, other) = match start {
+                        Some(_) => ('.', ')'),
+                        None => (bullet, if bullet == '-' { '*' } else { '-' }),
+                    };
+                    let delim = if after_list && self.last_delim == delim { other } else { delim };
+                    let loose = is_loose(&self.events[i - 1..]);
+                    if after_list {
+                        // a blank line would make the list before it loose
+                        let loose = self.last_list_loose;
+                        self.separate(loose);
+                    } else {
+                        self.separate_block();
+                    }
+                    self.lists.push(List { number: start, delim: delim, loose: loose });
+                    self.block_start = true;
+                    self.depth += 1;
+                }
+                Start(Tag::Link(dest, title)) => {
+                    if let Some(end) = self.autolink(i, &dest, &title) {
+                        i = end;
+                    } else {
+                        self.start_tag(Tag::Link(dest, title));
+                    }
+                }
+                Start(tag @ Tag::Emphasis) | Start(tag @ Tag::Strong) => {
+                    let delim = self.emphasis_delim(i - 1, tag == Tag::Strong);
+                    self.inline(&delim);
+                    self.emphasis.push(delim);
+                    self.depth += 1;
+                }
+                Start(Tag::Header(level)) => {
+                    // an empty setext heading would be an empty paragraph
+                    let empty = match self.events.get(i) {
+                        Some(&End(_)) => true,
+                        _ => false,
+                    };
+                    if self.opts.headings == HeadingStyle::Setext && level <= 2 && !empty {
+                        self.setext = Some(level);
+                    }
+                    self.start_tag(Tag::Header(level));
+                }
+                Start(tag) => self.start_tag(tag),
+                End(tag) => self.end_tag(tag),
+                Text(text) => {
+                    self.text(&text);
+                    // `!` right before a link would turn it into an image
+                    if text.ends_with('!') {
+                        if let Some(&Start(Tag::Link(_, _))) = self.events.get(i) {
+                            self.buf.pop();
+                            self.buf.push_str("\\!");
+                        }
+                    }
+                }
+                Html(html) => {
+                    // a block may come in pieces, split in the middle of a line
+                    let mut html = html.into_owned();
+                    while !html.ends_with('\n') {
+                        match self.events.get(i) {
+                            Some(&Html(ref more)) => html.push_str(more),
+                            _ => break,
+                        }
+                        i += 1;
+                    }
+                    // the end tag of a script, pre or style block comes separately
+                    let is_end = ["</script", "</pre", "</style"].iter().any(|tag| {
+                        html.get(..tag.len()).map_or(false, |start| start.eq_ignore_ascii_case(tag))
+                    });
+                    if is_end {
+                        self.separate(false);
+                    } else {
+                        self.separate_block();
+                    }
+                    self.raw_lines(&html);
+                }
+                InlineHtml(html) => self.inline(&html),
+                SoftBreak => match self.opts.wrap {
+                    Some(_) if !self.in_header => self.space(),
+                    _ => self.end_line(),
+                },
+                HardBreak => {
+                    self.inline("\\");
+                    self.end_line();
+                }
+                FootnoteReference(name) => self.inline(&format!("[^{}]", name)),
+                Mention(kind, name) => self.inline(&format!("{}{}", kind.sigil(), name)),
+                Emoji(shortcode, _) => self.inline(&format!(":{}:", shortcode)),
+                TaskListMarker(checked) => {
+                    self.inline(if checked { "[x] " } else { "[ ] " });
+                    // a loose item's first paragraph goes on the same line
+                    if let Some(&Start(Tag::Paragraph)) = self.events.get(i) {
+                        self.block_start = true;
+                    }
+                }
+            }
+            if self.depth == 0 && self.opts.links == LinkStyle::AfterBlock {
+                self.write_definitions();
+            }
+        }
+        self.end_line();
+        self.write_definitions();
+    }
+
+    fn start_tag(&mut self, tag: Tag<'a>) {
+        self.depth += 1;
+        match tag {
+            Tag::Paragraph => self.separate_block(),
+            Tag::Rule => {
+                self.separate_block();
+                // `* ***` on an item's first line would be one long rule
+                let in_star_list = self.lists.iter().any(|list| list.delim == '*');
+                self.inline(if in_star_list { "___" } else { "***" });
+                self.end_line();
+            }
+            Tag::Header(level) => {
+                self.separate_block();
+                self.ensure_line();
+                self.in_header = true;
+                if self.setext.is_none() {
+                    push_repeated(self.buf, '#', level as usize);
+                    self.buf.push(' ');
+                    self.line_start = false;
+                }
+            }
+            Tag::BlockQuote => {
+                self.separate_block();
+                self.push_container("> ".to_string(), "> ".to_string(), true);
+            }
+            Tag::Item => {
+                let (marker, loose) = match self.lists.last_mut() {
+                    Some(list) => {
+                        let marker = match list.number {
+                            Some(ref mut number) => {
+                                *number += 1;
+                                format!("{}{} ", *number - 1, list.delim)
+                            }
+                            None => format!("{} ", list.delim),
+                        };
+                        (marker, list.loose)
+                    }
+                    None => ("- ".to_string(), false),
+                };
+                self.separate(loose);
+                let indent = " ".repeat(marker.len());
+                self.push_container(marker, indent, loose);
+            }
+            Tag::FootnoteDefinition(name) => {
+                self.separate_block();
+                self.push_container(format!("[^{}]: ", name), String::new(), false);
+            }
+            Tag::Table(alignments) => {
+                self.separate_block();
+                self.in_table = true;
+                self.alignments = alignments;
+            }
+            Tag::TableHead | Tag::TableRow => self.inline("|"),
+            Tag::Link(_, _) => {
+                self.links += 1;
+                self.inline("[");
+            }
+            Tag::Image(_, _) => self.inline("!["),
+            Tag::Strikethrough => self.inline("~~"),
+            Tag::Spoiler => self.inline("||"),
+            _ => (),
+        }
+    }
+
+    fn end_tag(&mut self, tag: Tag) {
+        self.depth -= 1;
+        match tag {
+            Tag::Paragraph => self.end_line(),
+            Tag::Header(_) => {
+                let len = self.buf.trim_end_matches(' ').len();
+                self.buf.truncate(len);
+                self.in_header = false;
+                if let Some(level) = self.setext.take() {
+                    let prefix_len: usize = self.containers.iter().map(|c| c.indent.len()).sum();
+                    let width = self.buf[self.line_begin..].chars().count() - prefix_len;
+                    self.end_line();
+                    self.ensure_line();
+                    push_repeated(self.buf, if level == 1 { '=' } else { '-' }, cmp::max(3, width));
+                }
+                self.end_line();
+            }
+            Tag::BlockQuote | Tag::Item | Tag::FootnoteDefinition(_) => self.pop_container(),
+            Tag::List(_) => {
+                if let Some(list) = self.lists.pop() {
+                    self.last_delim = list.delim;
+                    self.last_list_loose = list.loose;
+                }
+                self.end_line();
+                self.block_start = false;
+            }
+            Tag::Table(_) => self.in_table = false,
+            Tag::TableHead => {
+                self.end_line();
+                self.ensure_line();
+                self.buf.push('|');
+                for alignment in &self.alignments {
+                    self.buf.push_str(match *alignment {
+                        Alignment::None => "---|",
+                        Alignment::Left => ":--|",
+                        Alignment::Center => ":-:|",
+                        Alignment::Right => "--:|",
+                    });
+                }
+                self.end_line();
+            }
+            Tag::TableRow => self.end_line(),
+            Tag::TableCell => self.inline("|"),
+            Tag::Emphasis | Tag::Strong => {
+                self.space_at = None;
+                if let Some(delim) = self.emphasis.pop() {
+                    self.buf.push_str(&delim);
+                }
+            }
+            Tag::Strikethrough => {
+                self.space_at = None;
+                self.buf.push_str("~~");
+            }
+            Tag::Spoiler => {
+                self.space_at = None;
+                self.buf.push_str("||");
+            }
+            Tag::Link(dest, title) => {
+                self.links -= 1;
+                self.link_end(&dest, &title);
+            }
+            Tag::Image(dest, title) => self.link_end(&dest, &title),
+            _ => ()
+        }
+    }
+}
+
+/// Iterate over an `Iterator` of `Event`s, and push Markdown source for them
+/// to a `String`.
+///
+/// Parsing the output again, with the same options, gives back the same
+/// events, though text may be split up differently.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{markdown, Event, Parser};
+///
+/// let parser = Parser::new("Some _emphasis_ and a\n[link](https://example.com)")
+///     .map(|event| match event {
+///         Event::Text(text) => Event::Text(text.to_uppercase().into()),
+///         event => event,
+///     });
+///
+/// let mut md = String::new();
+/// markdown::push_markdown(&mut md, parser);
+///
+/// assert_eq!(md, "SOME *EMPHASIS* AND A\n[LINK](https://example.com)\n");
+/// ```
+pub fn push_markdown<'a, I: Iterator<Item=Event<'a>>>(buf: &mut String, iter: I) {
+    push_markdown_with(buf, iter, &MarkdownOptions::default());
+}
+
+/// Like `push_markdown`, but with control over the style of the output.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{markdown, Parser};
+/// use pulldown_cmark::markdown::{HeadingStyle, MarkdownOptions};
+///
+/// let opts = MarkdownOptions {
+///     emphasis: '_',
+///     headings: HeadingStyle::Setext,
+///     ..MarkdownOptions::default()
+/// };
+/// let mut buf = String::new();
+/// markdown::push_markdown_with(&mut buf, Parser::new("# Hello *world*"), &opts);
+/// assert_eq!(buf, "Hello _world_\n=============\n");
+/// ```
+pub fn push_markdown_with<'a, I>(buf: &mut String, iter: I, opts: &MarkdownOptions)
+    where I: Iterator<Item=Event<'a>>
+{
+    let mut ctx = Ctx {
+        events: iter.collect(),
+        buf: buf,
+        opts: opts,
+        containers: Vec::new(),
+        lists: Vec::new(),
+        block_start: true,
+        line_open: false,
+        line_start: false,
+        line_digits: false,
+        in_header: false,
+        in_table: false,
+        alignments: Vec::new(),
+        last_delim: '-',
+        last_list_loose: false,
+        emphasis: Vec::new(),
+        depth: 0,
+        line_begin: 0,
+        space_at: None,
+        break_at: None,
+        setext: None,
+        links: 0,
+        definitions: Vec::new(),
+        written_definitions: 0,
+    };
+    ctx.run();
+}
@@ -0,0 +1,362 @@
+// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Renderer for the HTML subset Matrix allows in the `formatted_body` of
+//! messages, taking an iterator of events as input.
+
+use std::fmt::Write;
+
+use escape::{escape_html, escape_href};
+use render::{self, FootnoteNumbers};
+use parse::Event::{Start, End, Text, Html, InlineHtml, SoftBreak, HardBreak, FootnoteReference,
+    TaskListMarker, Mention, Emoji};
+use parse::{Event, Tag};
+
+/// How deeply elements may nest; the Matrix spec asks clients to cut off
+/// anything deeper than this.
+pub const MAX_DEPTH: usize = 100;
+
+// Link schemes the Matrix spec allows.
+const SCHEMES: [&'static str; 5] = ["https:", "http:", "ftp:", "mailto:", "magnet:"];
+
+fn is_allowed_link(dest: &str) -> bool {
+    SCHEMES.iter().any(|scheme| {
+        dest.get(..scheme.len()).map_or(false, |prefix| prefix.eq_ignore_ascii_case(scheme))
+    })
+}
+
+struct Ctx<'a, 'b, I> {
+    iter: I,
+    buf: &'b mut String,
+    // names of the elements each open tag started, closed when it ends
+    frames: Vec<Vec<&'static str>>,
+    // number of open elements
+    depth: usize,
+    in_head: bool,
+    // lines of an HTML block, shown as text
+    html: String,
+    numbers: FootnoteNumbers<'a>,
+}
+
+impl<'a, 'b, I: Iterator<Item=Event<'a>>> Ctx<'a, 'b, I> {
+    // Whether `n` more elements fit under the depth limit. Past it, tags are
+    // dropped and only their content is kept.
+    fn fits(&self, n: usize) -> bool {
+        self.depth + n <= MAX_DEPTH
+    }
+
+    // Open an element, ending along with the current tag, if it fits.
+    fn open(&mut self, name: &'static str, markup: &str) {
+        if !self.fits(1) {
+            return;
+        }
+        self.buf.push_str(markup);
+        self.depth += 1;
+        if let Some(frame) = self.frames.last_mut() {
+            frame.push(name);
+        }
+    }
+
+    // Raw HTML isn't passed through, so an HTML block becomes a paragraph of
+    // its source text.
+    fn flush_html(&mut self) {
+        if self.html.is_empty() {
+            return;
+        }
+        let html = self.html.split_off(0);
+        if !self.fits(1) {
+            escape_html(self.buf, &html, false);
+            return;
+        }
+        render::fresh_line(self.buf);
+        self.buf.push_str("<p>");
+        for (i, line) in html.trim_end_matches('\n').split('\n').enumerate() {
+            if i > 0 {
+                self.buf.push_str("<br />\n");
+            }
+            escape_html(self.buf, line, false);
+        }
+        self.buf.push_str("</p>\n");
+    }
+
+    pub fn run(&mut self) {
+        while let Some(event) = self.iter.next() {
+            if let Html(html) = event {
+                self.html.push_str(&html);
+                continue;
+            }
+            self.flush_html();
+            match event {
+                Start(tag) => {
+                    self.frames.push(Vec::new());
+                    self.start_tag(tag);
+                }
+                End(tag) => self.end_tag(tag),
+                Text(text) => escape_html(self.buf, &text, false),
+                Html(_) => (),
+                InlineHtml(html) => escape_html(self.buf, &html, false),
+                SoftBreak => self.buf.push('\n'),
+                HardBreak => {
+                    if self.fits(1) {
+                        self.buf.push_str("<br />\n");
+                    } else {
+                        self.buf.push('\n');
+                    }
+                }
+                FootnoteReference(name) => {
+                    let number = render::footnote_number(&mut self.numbers, name);
+                    if self.fits(1) {
+                        let _ = write!(self.buf, "<sup>{}</sup>", number);
+                    } else {
+                        let _ = write!(self.buf, "[{}]", number);
+                    }
+                }
+                // messages can't contain form elements
+                TaskListMarker(checked) => self.buf.push_str(if checked { "☑ " } else { "☐ " }),
+                // pills need the full ID, including the server
+                Mention(kind, name) => {
+                    self.buf.push(kind.sigil());
+                    escape_html(self.buf, &name, false);
+                }
+                Emoji(_, emoji) => escape_html(self.buf, &emoji, false),
+            }
+        }
+        self.flush_html();
+    }
+
+    fn start_tag(&mut self, tag: Tag<'a>) {
+        match tag {
+            Tag::Paragraph => {
+                render::fresh_line(self.buf);
+                self.open("p", "<p>");
+            }
+            Tag::Rule => {
+                render::fresh_line(self.buf);
+                if self.fits(1) {
+                    self.buf.push_str("<hr />\n");
+                }
+            }
+            Tag::Header(level) => {
+                render::fresh_line(self.buf);
+                match level {
+                    1 => self.open("h1", "<h1>"),
+                    2 => self.open("h2", "<h2>"),
+                    3 => self.open("h3", "<h3>"),
+                    4 => self.open("h4", "<h4>"),
+                    5 => self.open("h5", "<h5>"),
+                    _ => self.open("h6", "<h6>"),
+                }
+            }
+            Tag::Table(_) => {
+                render::fresh_line(self.buf);
+                self.open("table", "<table>");
+            }
+            Tag::TableHead => {
+                self.in_head = true;
+                self.open("thead", "<thead>");
+                self.open("tr", "<tr>");
+            }
+            Tag::TableRow => self.open("tr", "<tr>"),
+            Tag::TableCell => {
+                if self.in_head {
+                    self.open("th", "<th>");
+                } else {
+                    self.open("td", "<td>");
+                }
+            }
+            Tag::BlockQuote => {
+                render::fresh_line(self.buf);
+                self.open("blockquote", "<blockquote>\n");
+            }
+            Tag::CodeBlock(info) => {
+                render::fresh_line(self.buf);
+                let code = self.collect_text();
+                if !self.fits(2) {
+                    escape_html(self.buf, &code, false);
+                    return;
+                }
+                let lang = info.split(' ').next().unwrap_or("");
+                if lang.is_empty() {
+                    self.buf.push_str("<pre><code>");
+                } else {
+                    self.buf.push_str("<pre><code class=\"language-");
+                    escape_html(self.buf, lang, false);
+                    self.buf.push_str("\">");
+                }
+                escape_html(self.buf, &code, false);
+                self.buf.push_str("</code></pre>\n");
+            }
+            Tag::List(Some(1)) => {
+                render::fresh_line(self.buf);
+                self.open("ol", "<ol>\n");
+            }
+            Tag::List(Some(start)) => {
+                render::fresh_line(self.buf);
+                self.open("ol", &format!("<ol start=\"{}\">\n", start));
+            }
+            Tag::List(None) => {
+                render::fresh_line(self.buf);
+                self.open("ul", "<ul>\n");
+            }
+            Tag::Item => {
+                render::fresh_line(self.buf);
+                self.open("li", "<li>");
+            }
+            Tag::FootnoteDefinition(name) => {
+                render::fresh_line(self.buf);
+                let number = render::footnote_number(&mut self.numbers, name);
+                self.open("div", "<div>");
+                if self.fits(1) {
+                    let _ = write!(self.buf, "<sup>{}</sup>", number);
+                } else {
+                    let _ = write!(self.buf, "[{}] ", number);
+                }
+            }
+            Tag::Emphasis => self.open("em", "<em>"),
+            Tag::Strong => self.open("strong", "<strong>"),
+            Tag::Strikethrough => self.open("del", "<del>"),
+            Tag::Spoiler => self.open("span", "<span data-mx-spoiler>"),
+            Tag::Code => self.open("code", "<code>"),
+            // links with other schemes are dropped, keeping their text
+            Tag::Link(dest, title) => {
+                if is_allowed_link(&dest) {
+                    let markup = link_markup(&dest, &title);
+                    self.open("a", &markup);
+                }
+            }
+            // clients only load images from the content repository; other
+            // images link to their source instead
+            Tag::Image(dest, title) => {
+                if dest.starts_with("mxc://") {
+                    let alt = self.collect_text();
+                    if !self.fits(1) {
+                        escape_html(self.buf, &alt, false);
+                        return;
+                    }
+                    self.buf.push_str("<img src=\"");
+                    escape_href(self.buf, &dest);
+                    self.buf.push_str("\" alt=\"");
+                    escape_html(self.buf, &alt, false);
+                    if !title.is_empty() {
+                        self.buf.push_str("\" title=\"");
+                        escape_html(self.buf, &title, false);
+                    }
+                    self.buf.push_str("\" />");
+                } else if is_allowed_link(&dest) {
+                    let markup = link_markup(&dest, &title);
+                    self.open("a", &markup);
+                }
+            }
+        }
+    }
+
+    fn end_tag(&mut self, tag: Tag) {
+        if let Tag::TableHead = tag {
+            self.in_head = false;
+        }
+        let frame = match self.frames.pop() {
+            Some(frame) => frame,
+            None => return,
+        };
+        for name in frame.into_iter().rev() {
+            self.depth -= 1;
+            let _ = write!(self.buf, "</{}>", name);
+        }
+        match tag {
+            Tag::Paragraph | Tag::Header(_) | Tag::Table(_) | Tag::TableHead |
+            Tag::TableRow | Tag::BlockQuote | Tag::List(_) | Tag::Item |
+            Tag::FootnoteDefinition(_) => self.buf.push('\n'),
+            _ => (),
+        }
+    }
+
+    // The text up to the end of the current tag, consuming the end tag and
+    // the tag's frame.
+    fn collect_text(&mut self) -> String {
+        let mut text = String::new();
+        let mut nest = 0;
+        while let Some(event) = self.iter.next() {
+            match event {
+                Start(_) => nest += 1,
+                End(_) if nest == 0 => break,
+                End(_) => nest -= 1,
+                Text(more) | InlineHtml(more) | Emoji(_, more) => text.push_str(&more),
+                SoftBreak | HardBreak => text.push(' '),
+                _ => (),
+            }
+        }
+        self.frames.pop();
+        text
+    }
+}
+
+fn link_markup(dest: &str, title: &str) -> String {
+    let mut markup = String::from("<a href=\"");
+    escape_href(&mut markup, dest);
+    if !title.is_empty() {
+        markup.push_str("\" title=\"");
+        escape_html(&mut markup, title, false);
+    }
+    markup.push_str("\">");
+    markup
+}
+
+/// Iterate over an `Iterator` of `Event`s, generate HTML for the
+/// `formatted_body` of a Matrix message, and push it to a `String`.
+///
+/// Only the tags and attributes the Matrix spec allows are written, with
+/// every element closed, so the output can follow an `<mx-reply>` fallback
+/// as it is. Raw HTML is escaped, links are kept only for the allowed
+/// schemes, images only for `mxc://` sources, and elements nested deeper
+/// than `MAX_DEPTH` are dropped, keeping their text.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{matrix, Parser};
+///
+/// let markdown_str = r#"
+/// Some *emphasis* and <b>raw</b> [links](javascript:alert(1)).
+///
+/// ~~~rust
+/// let x = 1;
+/// ~~~
+/// "#;
+///
+/// let mut matrix_buf = String::new();
+/// matrix::push_matrix(&mut matrix_buf, Parser::new(markdown_str));
+///
+/// assert_eq!(matrix_buf, r#"<p>Some <em>emphasis</em> and &lt;b&gt;raw&lt;/b&gt; links.</p>
+/// <pre><code class="language-rust">let x = 1;
+/// </code></pre>
+/// "#);
+/// ```
+pub fn push_matrix<'a, I: Iterator<Item=Event<'a>>>(buf: &mut String, iter: I) {
+    let mut ctx = Ctx {
+        iter: iter,
+        buf: buf,
+        frames: Vec::new(),
+        depth: 0,
+        in_head: false,
+        html: String::new(),
+        numbers: FootnoteNumbers::new(),
+    };
+    ctx.run();
+}
@@ -0,0 +1,636 @@
+// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+// This is a copy of pulldown_cmark's html.rs, modified to work in GTK.
+
+//! Pango markup renderer that takes an iterator of events as input, for GTK labels.
+
+use std::borrow::Cow;
+use std::cmp;
+use std::mem;
+
+use unicode_width::UnicodeWidthStr;
+
+use escape::{escape_html, escape_href};
+use sanitize;
+use render::{self, BULLETS, FootnoteNumbers};
+use parse::Event::{Start, End, Text, Html, InlineHtml, SoftBreak, HardBreak, FootnoteReference,
+    TaskListMarker, Mention, Emoji};
+use parse::{Alignment, Event, Tag};
+
+pub use sanitize::{RawHtml, Whitelist};
+
+/// Markup wrapped around the text of each heading level.
+///
+/// The default table shrinks from a doubled `<big>` for level 1 to
+/// small caps for level 6.
+#[derive(Clone, Debug)]
+pub struct HeadingStyles {
+    levels: [(String, String); 6],
+}
+
+impl HeadingStyles {
+    /// Use `open` and `close` around headings of `level`, from 1 to 6.
+    /// Levels out of range are ignored.
+    pub fn level(mut self, level: i32, open: &str, close: &str) -> HeadingStyles {
+        if level >= 1 && level <= 6 {
+            self.levels[level as usize - 1] = (open.to_string(), close.to_string());
+        }
+        self
+    }
+
+    fn get(&self, level: i32) -> &(String, String) {
+        let index = cmp::max(1, cmp::min(level, 6)) as usize - 1;
+        &self.levels[index]
+    }
+}
+
+impl Default for HeadingStyles {
+    fn default() -> HeadingStyles {
+        let style = |open: &str, close: &str| (open.to_string(), close.to_string());
+        HeadingStyles {
+            levels: [
+                style("<big><big><b>", "</b></big></big>"),
+                style("<big><big>", "</big></big>"),
+                style("<big><b>", "</b></big>"),
+                style("<b>", "</b>"),
+                style("<i>", "</i>"),
+                style("<span font_variant=\"smallcaps\">", "</span>"),
+            ]
+        }
+    }
+}
+
+/// How soft line breaks in paragraphs are rendered.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SoftBreaks {
+    /// Keep the line break, as written in the source.
+    Newline,
+    /// Join the lines with a space, like HTML does.
+    Space,
+}
+
+/// How images are rendered, since Pango can't show them inline.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Images {
+    /// Render the alt text as a link to the image.
+    AltLink,
+    /// Replace the image, alt text and all, with this markup.
+    Placeholder(String),
+}
+
+/// Options for `push_html_with`.
+#[derive(Clone, Debug)]
+pub struct RenderOptions {
+    /// Font family for code spans and code blocks; `None` uses `<tt>`.
+    pub monospace_font: Option<String>,
+    /// Markup for each heading level.
+    pub headings: HeadingStyles,
+    /// Markup put in front of each line of a block quote.
+    pub quote_marker: String,
+    /// How soft line breaks are rendered.
+    pub soft_breaks: SoftBreaks,
+    /// What happens to raw HTML in the source.
+    pub raw_html: RawHtml,
+    /// How images are rendered.
+    pub images: Images,
+    /// Length in characters of the line drawn for a horizontal rule.
+    pub rule_width: usize,
+    /// Hex color used for both the text and the background of spoilers,
+    /// hiding them until they are selected.
+    pub spoiler_color: String,
+}
+
+impl Default for RenderOptions {
+    fn default() -> RenderOptions {
+        RenderOptions {
+            monospace_font: None,
+            headings: HeadingStyles::default(),
+            quote_marker: "<span foreground=\"#888888\">┃</span> ".to_string(),
+            soft_breaks: SoftBreaks::Newline,
+            raw_html: RawHtml::Escape,
+            images: Images::AltLink,
+            rule_width: 20,
+            spoiler_color: "#444444".to_string(),
+        }
+    }
+}
+
+// A table whose cells are collected first, so the columns can be sized to fit.
+struct Table {
+    alignments: Vec<Alignment>,
+    // markup and display width of each cell, row by row
+    rows: Vec<Vec<(String, usize)>>,
+    has_head: bool,
+    cell_start: usize,
+}
+
+// Number of columns the markup takes up in a monospace font, skipping tags
+// and counting each entity as one column. Wide characters, like most CJK and
+// emoji, take up two.
+fn markup_width(markup: &str) -> usize {
+    let mut text = String::new();
+    let mut chars = markup.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '<' => while chars.next().map_or(false, |c| c != '>') { },
+            '&' => {
+                while chars.next().map_or(false, |c| c != ';') { }
+                text.push(' ');
+            }
+            c => text.push(c),
+        }
+    }
+    UnicodeWidthStr::width(&*text)
+}
+
+fn push_repeated(buf: &mut String, s: &str, count: usize) {
+    for _ in 0..count {
+        buf.push_str(s);
+    }
+}
+
+struct Ctx<'a, 'b, 'c, I, F> {
+    iter: I,
+    buf: &'b mut String,
+    opts: &'c RenderOptions,
+    // called with the destination and title of each image
+    on_image: F,
+    // number of open links, since Pango doesn't nest them
+    links: usize,
+    // whitelisted raw HTML tags that are still open
+    raw_open: Vec<String>,
+    // number of open raw HTML tags when each open tag was started
+    raw_marks: Vec<usize>,
+    // next item number for each open list, `None` for bullet lists
+    lists: Vec<Option<usize>>,
+    // buffer offsets where the open block quotes begin
+    quotes: Vec<usize>,
+    table: Option<Table>,
+    numbers: FootnoteNumbers<'a>,
+    // name and buffer offset of the footnote definition being collected
+    footnote: Option<(Cow<'a, str>, usize)>,
+    // collected footnote definitions, written out after the body
+    footnotes: Vec<(Cow<'a, str>, String)>,
+}
+
+impl<'a, 'b, 'c, I, F> Ctx<'a, 'b, 'c, I, F>
+        where I: Iterator<Item=Event<'a>>, F: FnMut(&str, &str) {
+    fn start_monospace(&mut self) {
+        match self.opts.monospace_font {
+            Some(ref font) => {
+                self.buf.push_str("<span font_family=\"");
+                escape_html(self.buf, font, false);
+                self.buf.push_str("\">");
+            }
+            None => self.buf.push_str("<tt>"),
+        }
+    }
+
+    fn end_monospace(&mut self) {
+        match self.opts.monospace_font {
+            Some(_) => self.buf.push_str("</span>"),
+            None => self.buf.push_str("</tt>"),
+        }
+    }
+
+    fn list_item_marker(&mut self) {
+        let depth = self.lists.len();
+        for _ in 1..depth {
+            self.buf.push_str("  ");
+        }
+        match self.lists.last_mut() {
+            Some(&mut Some(ref mut number)) => {
+                self.buf.push_str(&*format!("{}. ", number));
+                *number += 1;
+            }
+            _ => {
+                self.buf.push_str(BULLETS[depth.saturating_sub(1) % BULLETS.len()]);
+                self.buf.push(' ');
+            }
+        }
+    }
+
+    // Prefix every line written since the block quote started with the marker.
+    fn end_quote(&mut self) {
+        render::fresh_line(self.buf);
+        let start = match self.quotes.pop() {
+            Some(start) => start,
+            None => return
+        };
+        let quoted = self.buf.split_off(start);
+        for line in quoted.split_terminator('\n') {
+            self.buf.push_str(&self.opts.quote_marker);
+            self.buf.push_str(line);
+            self.buf.push('\n');
+        }
+    }
+
+    fn end_table_cell(&mut self) {
+        if let Some(ref mut table) = self.table {
+            let markup = self.buf.split_off(table.cell_start).trim().to_string();
+            let width = markup_width(&markup);
+            if let Some(row) = table.rows.last_mut() {
+                row.push((markup, width));
+            }
+        }
+    }
+
+    // Lay out the collected table in a box, with every column as wide as its widest cell.
+    fn end_table(&mut self) {
+        let table = match self.table.take() {
+            Some(table) => table,
+            None => return
+        };
+        let n_cols = table.rows.iter().map(|row| row.len())
+            .fold(table.alignments.len(), cmp::max);
+        let mut widths = vec![0; n_cols];
+        for row in &table.rows {
+            for (i, &(_, width)) in row.iter().enumerate() {
+                widths[i] = cmp::max(widths[i], width);
+            }
+        }
+
+        self.start_monospace();
+        self.rule_line(&widths, "┌", "┬", "┐");
+        for (i, row) in table.rows.iter().enumerate() {
+            let is_head = table.has_head && i == 0;
+            self.buf.push_str("│");
+            for (j, &width) in widths.iter().enumerate() {
+                let (markup, cell_width) = match row.get(j) {
+                    Some(&(ref markup, cell_width)) => (&markup[..], cell_width),
+                    None => ("", 0),
+                };
+                let padding = width - cell_width;
+                let before = match table.alignments.get(j) {
+                    Some(&Alignment::Right) => padding,
+                    Some(&Alignment::Center) => padding / 2,
+                    _ => 0,
+                };
+                self.buf.push(' ');
+                push_repeated(self.buf, " ", before);
+                if is_head && !markup.is_empty() {
+                    self.buf.push_str("<b>");
+                    self.buf.push_str(markup);
+                    self.buf.push_str("</b>");
+                } else {
+                    self.buf.push_str(markup);
+                }
+                push_repeated(self.buf, " ", padding - before);
+                self.buf.push_str(" │");
+            }
+            self.buf.push('\n');
+            if is_head {
+                self.rule_line(&widths, "├", "┼", "┤");
+            }
+        }
+        self.rule_line(&widths, "└", "┴", "┘");
+        // keep the closing tag on the last line, so quote markers stay outside it
+        self.buf.pop();
+        self.end_monospace();
+        self.buf.push('\n');
+    }
+
+    fn rule_line(&mut self, widths: &[usize], left: &str, middle: &str, right: &str) {
+        self.buf.push_str(left);
+        for (i, &width) in widths.iter().enumerate() {
+            if i > 0 {
+                self.buf.push_str(middle);
+            }
+            push_repeated(self.buf, "─", width + 2);
+        }
+        self.buf.push_str(right);
+        self.buf.push('\n');
+    }
+
+    fn end_footnote_definition(&mut self) {
+        if let Some((name, start)) = self.footnote.take() {
+            let content = self.buf.split_off(start);
+            self.footnotes.push((name, content.trim().to_string()));
+        }
+    }
+
+    // Write the footnote definitions in a smaller font, numbered to match the
+    // references. Definitions nobody referenced are numbered after the rest.
+    fn write_footnotes(&mut self) {
+        if self.footnotes.is_empty() {
+            return;
+        }
+        let mut footnotes = mem::replace(&mut self.footnotes, Vec::new());
+        for &(ref name, _) in &footnotes {
+            render::footnote_number(&mut self.numbers, name.clone());
+        }
+        footnotes.sort_by_key(|&(ref name, _)| self.numbers[name]);
+        render::fresh_line(self.buf);
+        self.buf.push_str("<small>");
+        for (i, (name, content)) in footnotes.into_iter().enumerate() {
+            if i > 0 {
+                self.buf.push('\n');
+            }
+            self.buf.push_str(&*format!("<sup>{}</sup> ", self.numbers[&name]));
+            self.buf.push_str(&content);
+        }
+        self.buf.push_str("</small>\n");
+    }
+
+    pub fn run(&mut self) {
+        while let Some(event) = self.iter.next() {
+            match event {
+                Start(tag) => {
+                    self.raw_marks.push(self.raw_open.len());
+                    self.start_tag(tag);
+                }
+                End(tag) => {
+                    // raw HTML can't leave tags open across our own markup
+                    let depth = self.raw_marks.pop().unwrap_or(0);
+                    sanitize::close_tags(self.buf, &mut self.raw_open, depth);
+                    self.end_tag(tag);
+                }
+                Text(text) => escape_html(self.buf, &text, false),
+                Html(html) |
+                InlineHtml(html) => match self.opts.raw_html {
+                    RawHtml::Escape => escape_html(self.buf, &html, false),
+                    RawHtml::Whitelist(ref whitelist) => {
+                        let depth = self.raw_marks.last().cloned().unwrap_or(0);
+                        sanitize::push_html(self.buf, &html, whitelist, &mut self.raw_open, depth);
+                    }
+                },
+                SoftBreak => match self.opts.soft_breaks {
+                    SoftBreaks::Newline => self.buf.push('\n'),
+                    SoftBreaks::Space => self.buf.push(' '),
+                },
+                // Pango has no line break element
+                HardBreak => self.buf.push('\n'),
+                FootnoteReference(name) => {
+                    let number = render::footnote_number(&mut self.numbers, name);
+                    self.buf.push_str(&*format!("<sup>{}</sup>", number));
+                },
+                TaskListMarker(checked) => self.buf.push_str(if checked { "☑ " } else { "☐ " }),
+                Mention(kind, name) => {
+                    self.buf.push_str("<b>");
+                    self.buf.push(kind.sigil());
+                    escape_html(self.buf, &name, false);
+                    self.buf.push_str("</b>");
+                }
+                Emoji(_, emoji) => escape_html(self.buf, &emoji, false),
+            }
+        }
+        sanitize::close_tags(self.buf, &mut self.raw_open, 0);
+        self.write_footnotes();
+    }
+
+    fn start_tag(&mut self, tag: Tag<'a>) {
+        match tag {
+            Tag::Header(level) => {
+                render::fresh_line(self.buf);
+                self.buf.push_str(&self.opts.headings.get(level).0);
+            }
+            Tag::Rule => {
+                render::fresh_line(self.buf);
+                self.buf.push_str("<span foreground=\"#888888\">");
+                push_repeated(self.buf, "─", self.opts.rule_width);
+                self.buf.push_str("</span>\n");
+            }
+            Tag::CodeBlock(_) => {
+                render::fresh_line(self.buf);
+                self.start_monospace();
+            }
+            Tag::BlockQuote => {
+                render::fresh_line(self.buf);
+                let start = self.buf.len();
+                self.quotes.push(start);
+            }
+            Tag::List(start) => {
+                render::fresh_line(self.buf);
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                render::fresh_line(self.buf);
+                self.list_item_marker();
+            }
+            Tag::Table(alignments) => {
+                render::fresh_line(self.buf);
+                self.table = Some(Table {
+                    alignments: alignments,
+                    rows: Vec::new(),
+                    has_head: false,
+                    cell_start: 0,
+                });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(ref mut table) = self.table {
+                    table.has_head |= tag == Tag::TableHead;
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::TableCell => {
+                if let Some(ref mut table) = self.table {
+                    table.cell_start = self.buf.len();
+                }
+            }
+            Tag::FootnoteDefinition(name) => {
+                let start = self.buf.len();
+                self.footnote = Some((name, start));
+            }
+            Tag::Emphasis => self.buf.push_str("<i>"),
+            Tag::Strong => self.buf.push_str("<b>"),
+            Tag::Strikethrough => self.buf.push_str("<s>"),
+            Tag::Spoiler => {
+                self.buf.push_str("<span foreground=\"");
+                escape_html(self.buf, &self.opts.spoiler_color, false);
+                self.buf.push_str("\" background=\"");
+                escape_html(self.buf, &self.opts.spoiler_color, false);
+                self.buf.push_str("\">");
+            }
+            Tag::Code => self.start_monospace(),
+            Tag::Link(dest, title) => self.start_link(&dest, &title),
+            Tag::Image(dest, title) => {
+                (self.on_image)(&dest, &title);
+                let opts = self.opts;
+                match opts.images {
+                    Images::AltLink => self.start_link(&dest, &title),
+                    Images::Placeholder(ref markup) => {
+                        self.buf.push_str(markup);
+                        self.skip_image();
+                    }
+                }
+            }
+            _ => ()
+        }
+    }
+
+    fn start_link(&mut self, dest: &str, title: &str) {
+        self.links += 1;
+        if self.links > 1 {
+            return;
+        }
+        self.buf.push_str("<a href=\"");
+        escape_href(self.buf, dest);
+        if !title.is_empty() {
+            self.buf.push_str("\" title=\"");
+            escape_html(self.buf, title, false);
+        }
+        self.buf.push_str("\">");
+    }
+
+    fn end_link(&mut self) {
+        self.links -= 1;
+        if self.links == 0 {
+            self.buf.push_str("</a>");
+        }
+    }
+
+    // Drop the alt text of an image, up to and including its end tag.
+    fn skip_image(&mut self) {
+        let mut nest = 0;
+        while let Some(event) = self.iter.next() {
+            match event {
+                Start(_) => nest += 1,
+                End(_) if nest == 0 => break,
+                End(_) => nest -= 1,
+                _ => ()
+            }
+        }
+        self.raw_marks.pop();
+    }
+
+    fn end_tag(&mut self, tag: Tag) {
+        match tag {
+            Tag::Header(level) => self.buf.push_str(&self.opts.headings.get(level).1),
+            Tag::CodeBlock(_) => {
+                self.end_monospace();
+                self.buf.push('\n');
+            }
+            Tag::BlockQuote => self.end_quote(),
+            Tag::List(_) => {
+                self.lists.pop();
+                render::fresh_line(self.buf);
+            }
+            Tag::Table(_) => self.end_table(),
+            Tag::TableCell => self.end_table_cell(),
+            Tag::FootnoteDefinition(_) => self.end_footnote_definition(),
+            Tag::Emphasis => self.buf.push_str("</i>"),
+            Tag::Strong => self.buf.push_str("</b>"),
+            Tag::Strikethrough => self.buf.push_str("</s>"),
+            Tag::Spoiler => self.buf.push_str("</span>"),
+            Tag::Code => self.end_monospace(),
+            Tag::Link(_, _) | Tag::Image(_, _) => self.end_link(),
+            _ => ()
+        }
+    }
+}
+
+/// Iterate over an `Iterator` of `Event`s, generate Pango markup for each
+/// `Event`, and push it to a `String`.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{pango, Parser};
+///
+/// let markdown_str = r#"
+/// hello
+/// =====
+///
+/// * alpha
+/// * beta
+/// "#;
+/// let parser = Parser::new(markdown_str);
+///
+/// let mut markup = String::new();
+/// pango::push_html(&mut markup, parser);
+///
+/// assert_eq!(markup, r#"<big><big><b>hello</b></big></big>
+/// • alpha
+/// • beta
+/// "#);
+/// ```
+pub fn push_html<'a, I: Iterator<Item=Event<'a>>>(buf: &mut String, iter: I) {
+    push_html_with(buf, iter, &RenderOptions::default());
+}
+
+/// Like `push_html`, but rendered according to `opts`.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{pango, Parser};
+/// use pulldown_cmark::pango::{RenderOptions, RawHtml, Whitelist};
+///
+/// let opts = RenderOptions {
+///     monospace_font: Some("Fira Mono".to_string()),
+///     raw_html: RawHtml::Whitelist(Whitelist::pango()),
+///     ..RenderOptions::default()
+/// };
+/// let parser = Parser::new("<b>bold</b> <blink>`code`</blink>");
+///
+/// let mut markup = String::new();
+/// pango::push_html_with(&mut markup, parser, &opts);
+///
+/// assert_eq!(markup, "<b>bold</b> &lt;blink&gt;<span font_family=\"Fira Mono\">code</span>&lt;/blink&gt;");
+/// ```
+pub fn push_html_with<'a, I>(buf: &mut String, iter: I, opts: &RenderOptions)
+        where I: Iterator<Item=Event<'a>> {
+    push_html_with_images(buf, iter, opts, |_, _| ());
+}
+
+/// Like `push_html_with`, calling `on_image` with the destination and title
+/// of every image, so that previews can be shown next to the markup.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{pango, Parser};
+/// use pulldown_cmark::pango::{Images, RenderOptions};
+///
+/// let opts = RenderOptions {
+///     images: Images::Placeholder("[image]".to_string()),
+///     ..RenderOptions::default()
+/// };
+/// let parser = Parser::new("![a cat](cat.png \"Felix\")");
+///
+/// let mut markup = String::new();
+/// let mut images = Vec::new();
+/// pango::push_html_with_images(&mut markup, parser, &opts, |dest, title| {
+///     images.push((dest.to_string(), title.to_string()));
+/// });
+///
+/// assert_eq!(markup, "[image]");
+/// assert_eq!(images, vec![("cat.png".to_string(), "Felix".to_string())]);
+/// ```
+pub fn push_html_with_images<'a, I, F>(buf: &mut String, iter: I, opts: &RenderOptions, on_image: F)
+        where I: Iterator<Item=Event<'a>>, F: FnMut(&str, &str) {
+    let mut ctx = Ctx {
+        iter: iter,
+        buf: buf,
+        opts: opts,
+        on_image: on_image,
+        links: 0,
+        raw_open: Vec::new(),
+        raw_marks: Vec::new(),
+        lists: Vec::new(),
+        quotes: Vec::new(),
+        table: None,
+        numbers: FootnoteNumbers::new(),
+        footnote: None,
+        footnotes: Vec::new(),
+    };
+    ctx.run();
+}
@@ -21,6 +21,8 @@
 //! Raw parser, for doing a single pass over input.
 
 use scanners::*;
+use links::BareLinks;
+use emoji;
 use utils;
 use std::borrow::Cow;
 use std::borrow::Cow::{Borrowed};
@@ -28,6 +30,9 @@ use std::collections::{HashMap, HashSet};
 use std::collections::hash_map::Entry;
 use std::cmp;
 
+// Maps an emoji shortcode to the text it stands for.
+pub type EmojiResolver<'a> = Box<dyn Fn(&str) -> Option<String> + 'a>;
+
 #[derive(PartialEq, Debug)]
 enum State {
     StartBlock,
@@ -69,6 +74,15 @@ pub struct RawParser<'a> {
     fence_count: usize,
     fence_indent: usize,
 
+    // checkbox of the task list item just started, emitted after its Start
+    task_marker: Option<bool>,
+
+    // looks up emoji shortcodes instead of the built-in table
+    emoji_resolver: Option<EmojiResolver<'a>>,
+
+    // links that mentions and shortcodes have to stay out of, with autolinking
+    bare_links: BareLinks,
+
     // info, used in second pass
     loose_lists: HashSet<usize>,  // offset is at list marker
     links: HashMap<String, (Cow<'a, str>, Cow<'a, str>)>,
@@ -80,6 +94,7 @@ pub struct ParseInfo<'a> {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Tag<'a> {
     // block-level tags
     Paragraph,
@@ -105,6 +120,11 @@ pub enum Tag<'a> {
     // span-level tags
     Emphasis,
     Strong,
+    /// Struck-through text, only produced with `OPTION_ENABLE_STRIKETHROUGH`.
+    Strikethrough,
+    /// Text hidden until the reader asks for it, only produced with
+    /// `OPTION_ENABLE_SPOILERS`.
+    Spoiler,
     Code,
 
     /// A link. The first field is the destination URL, the second is a title
@@ -115,6 +135,7 @@ pub enum Tag<'a> {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Event<'a> {
     Start(Tag<'a>),
     End(Tag<'a>),
@@ -124,9 +145,20 @@ pub enum Event<'a> {
     FootnoteReference(Cow<'a, str>),
     SoftBreak,
     HardBreak,
+    /// The checkbox of a task list item, right after its `Start(Tag::Item)`;
+    /// true if it is checked. Only produced with `OPTION_ENABLE_TASKLISTS`.
+    TaskListMarker(bool),
+    /// An `@user` or `#channel` mention, with the name after the sigil. Only
+    /// produced with `OPTION_ENABLE_MENTIONS`.
+    Mention(MentionKind, Cow<'a, str>),
+    /// An emoji shortcode such as `:smile:`. The first field is the shortcode
+    /// without its colons, the second the emoji it resolved to. Only produced
+    /// with `OPTION_ENABLE_EMOJI`.
+    Emoji(Cow<'a, str>, Cow<'a, str>),
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Alignment {
     None,
     Left,
@@ -134,11 +166,55 @@ pub enum Alignment {
     Right,
 }
 
+/// What a `Mention` refers to, given by its sigil.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MentionKind {
+    /// `@user`
+    User,
+    /// `#channel`
+    Channel,
+}
+
+impl MentionKind {
+    /// The character a mention of this kind starts with.
+    pub fn sigil(&self) -> char {
+        match *self {
+            MentionKind::User => '@',
+            MentionKind::Channel => '#',
+        }
+    }
+}
+
 bitflags! {
     pub struct Options: u32 {
         const OPTION_FIRST_PASS = 1 << 0;
         const OPTION_ENABLE_TABLES = 1 << 1;
         const OPTION_ENABLE_FOOTNOTES = 1 << 2;
+        const OPTION_ENABLE_AUTOLINK = 1 << 3;
+        const OPTION_ENABLE_STRIKETHROUGH = 1 << 4;
+        const OPTION_ENABLE_TASKLISTS = 1 << 5;
+        const OPTION_ENABLE_MENTIONS = 1 << 6;
+        const OPTION_ENABLE_EMOJI = 1 << 7;
+        const OPTION_ENABLE_SPOILERS = 1 << 8;
+    }
+}
+
+// Options serialize as their bits, as bitflags doesn't support serde.
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for Options {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.bits())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for Options {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Options, D::Error> {
+        use serde::de::Error;
+
+        let bits = u32::deserialize(deserializer)?;
+        Options::from_bits(bits).ok_or_else(|| D::Error::custom(format!("unknown options: {:#x}", bits)))
     }
 }
 
@@ -162,6 +238,10 @@ impl<'a> RawParser<'a> {
             fence_count: 0,
             fence_indent: 0,
 
+            task_marker: None,
+            emoji_resolver: None,
+            bare_links: BareLinks::new(),
+
             // info, used in second pass
             loose_lists: HashSet::new(),
             links: links,
@@ -181,6 +261,11 @@ impl<'a> RawParser<'a> {
         self.off
     }
 
+    // resolve emoji shortcodes with `resolver` instead of the built-in table
+    pub fn set_emoji_resolver(&mut self, resolver: EmojiResolver<'a>) {
+        self.emoji_resolver = Some(resolver);
+    }
+
     // extract info from parser on finish
     pub fn get_info(self) -> ParseInfo<'a> {
         ParseInfo {
@@ -196,6 +281,21 @@ impl<'a> RawParser<'a> {
             for &c in b"\x00\t\n\r_\\&*[!`<" {
                 self.active_tab[c as usize] = 1;
             }
+            if self.opts.contains(OPTION_ENABLE_STRIKETHROUGH) {
+                self.active_tab[b'~' as usize] = 1;
+            }
+            if self.opts.contains(OPTION_ENABLE_MENTIONS) {
+                self.active_tab[b'@' as usize] = 1;
+                self.active_tab[b'#' as usize] = 1;
+            }
+            if self.opts.contains(OPTION_ENABLE_EMOJI) {
+                self.active_tab[b':' as usize] = 1;
+            }
+            // table cells end at a `|` before their inline content is
+            // parsed, so spoilers can't be written inside them
+            if self.opts.contains(OPTION_ENABLE_SPOILERS) {
+                self.active_tab[b'|' as usize] = 1;
+            }
         }
     }
 
@@ -612,6 +712,18 @@ impl<'a> RawParser<'a> {
                     let (n, space) = scan_leading_space(self.text, self.off);
                     self.off += n;
                     self.leading_space = space;
+                    if self.opts.contains(OPTION_ENABLE_TASKLISTS) {
+                        if let Some((n, checked)) = scan_task_marker(&self.text[self.off ..]) {
+                            // only a paragraph can follow the checkbox, however
+                            // it's indented; a heading, rule or other block
+                            // leaves the brackets as text
+                            if !self.is_inline_block_end(&self.text[self.off + n ..], 0) {
+                                self.off += n;
+                                self.leading_space = 0;
+                                self.task_marker = Some(checked);
+                            }
+                        }
+                    }
                 }
                 self.containers.push(Container::ListItem(indent));
                 self.start(Tag::Item, self.text.len(), 0)
@@ -1007,6 +1119,10 @@ impl<'a> RawParser<'a> {
             b'&' => self.char_entity(),
             b'_' |
             b'*' => self.char_emphasis(),
+            b'~' => self.char_delimited(Tag::Strikethrough, 1, 2),
+            b'|' => self.char_delimited(Tag::Spoiler, 2, 2),
+            b'@' | b'#' => self.char_mention(),
+            b':' => self.char_emoji(),
             b'[' if self.opts.contains(OPTION_ENABLE_FOOTNOTES) => self.char_link_footnote(),
             b'[' | b'!' => self.char_link(),
             b'`' => self.char_backtick(),
@@ -1072,18 +1188,7 @@ impl<'a> RawParser<'a> {
         let mut i = self.off + n;
         while i < limit {
             let c2 = data.as_bytes()[i];
-            if c2 == b'\n' && !is_escaped(data, i) {
-                let (_, complete, space) = self.scan_containers(&self.text[i..]);
-                if !complete {
-                    i += 1;
-                    continue;
-                }
-                if self.is_inline_block_end(&self.text[i + 1 .. limit], space) {
-                    return None
-                } else {
-                    i += 1;
-                }
-            } else if c2 == c && !is_escaped(data, i) {
+            if c2 == c && !is_escaped(data, i) {
                 let (mut n2, can_open, can_close) = compute_open_close(data, i, c);
                 if can_close {
                     loop {
@@ -1111,39 +1216,144 @@ impl<'a> RawParser<'a> {
                     stack.push(n2);
                 }
                 i += n2;
-            } else if c2 == b'`' {
-                let (n, beg, _) = self.scan_inline_code(&self.text[i..limit]);
-                if n != 0 {
-                    i += n;
-                } else {
-                    i += beg;
-                }
-            } else if c2 == b'<' {
-                let n = self.scan_autolink_or_html(&self.text[i..limit]);
-                if n != 0 {
-                    i += n;
-                } else {
-                    i += 1;
+            } else {
+                match self.skip_inline(data, i) {
+                    Some(n) => i += n,
+                    None => return None
                 }
-            } else if c2 == b'[' {
-                if self.opts.contains(OPTION_ENABLE_FOOTNOTES) {
-                    if let Some((_, n)) = self.parse_footnote(&self.text[i..limit]) {
-                        i += n;
-                        continue;
+            }
+        }
+        None
+    }
+
+    // A span delimited by matching runs of `min` to `max` copies of the current
+    // character, like GFM strikethrough. Unlike emphasis, the runs must be the
+    // same length, and a run that doesn't match is taken literally.
+    fn char_delimited(&mut self, tag: Tag<'a>, min: usize, max: usize) -> Option<Event<'a>> {
+        let limit = self.limit();
+        let data = &self.text[..limit];
+
+        let c = data.as_bytes()[self.off];
+        let (n, can_open, _can_close) = compute_open_close(data, self.off, c);
+        // skip the whole run, so its tail isn't mistaken for a shorter one
+        let skip = self.off + n - 1;
+        if !can_open || n < min || n > max {
+            self.off = skip;
+            return None;
+        }
+        // runs of the same length nested inside pair up with each other first
+        let mut nest = 1;
+        let mut i = self.off + n;
+        while i < limit {
+            let c2 = data.as_bytes()[i];
+            if c2 == c && !is_escaped(data, i) {
+                let (n2, can_open, can_close) = compute_open_close(data, i, c);
+                if n2 == n && can_close {
+                    nest -= 1;
+                    if nest == 0 {
+                        self.off += n;
+                        return Some(self.start(tag, i, i + n));
                     }
+                } else if n2 == n && can_open {
+                    nest += 1;
                 }
-                if let Some((_, _, _, n)) = self.parse_link(&self.text[i..limit], false) {
-                    i += n;
-                } else {
-                    i += 1;
-                }
+                i += n2;
             } else {
-                i += 1;
+                match self.skip_inline(data, i) {
+                    Some(n) => i += n,
+                    None => break
+                }
             }
         }
+        self.off = skip;
         None
     }
 
+    fn char_mention(&mut self) -> Option<Event<'a>> {
+        // the text of a link can't link anywhere else
+        let in_link = self.stack.iter().any(|&(ref tag, _, _)| match *tag {
+            Tag::Link(_, _) | Tag::Image(_, _) => true,
+            _ => false
+        });
+        if in_link {
+            return None;
+        }
+        let limit = self.limit();
+        let n = scan_mention(&self.text[..limit], self.off);
+        if n == 0 {
+            return None;
+        }
+        // nor can a URL or email address that's going to be autolinked
+        if self.opts.contains(OPTION_ENABLE_AUTOLINK) && self.bare_links.contains(self.text, self.off) {
+            return None;
+        }
+        let kind = if self.text.as_bytes()[self.off] == b'@' {
+            MentionKind::User
+        } else {
+            MentionKind::Channel
+        };
+        let name = &self.text[self.off + 1 .. self.off + 1 + n];
+        self.off += 1 + n;
+        Some(Event::Mention(kind, Borrowed(name)))
+    }
+
+    // Unknown shortcodes are left as text.
+    fn char_emoji(&mut self) -> Option<Event<'a>> {
+        let limit = self.limit();
+        let n = scan_emoji_shortcode(&self.text[..limit], self.off);
+        if n == 0 {
+            return None;
+        }
+        let shortcode = &self.text[self.off + 1 .. self.off + 1 + n];
+        let emoji = match self.emoji_resolver {
+            Some(ref resolver) => resolver(shortcode).map(Cow::Owned),
+            None => emoji::get_emoji(shortcode).map(Borrowed),
+        };
+        let emoji = match emoji {
+            Some(emoji) => emoji,
+            None => return None
+        };
+        // a URL that's going to be autolinked keeps its colons
+        if self.opts.contains(OPTION_ENABLE_AUTOLINK) && self.bare_links.contains(self.text, self.off) {
+            return None;
+        }
+        self.off += n + 2;
+        Some(Event::Emoji(Borrowed(shortcode), emoji))
+    }
+
+    // Number of bytes to skip over at `i` while looking for a closing delimiter,
+    // stepping over the code spans, autolinks, HTML and links that delimiters
+    // can't pair across. None if the paragraph ends at `i`.
+    fn skip_inline(&self, data: &str, i: usize) -> Option<usize> {
+        let limit = data.len();
+        let c = data.as_bytes()[i];
+        if c == b'\n' && !is_escaped(data, i) {
+            let (_, complete, space) = self.scan_containers(&self.text[i..]);
+            if complete && self.is_inline_block_end(&self.text[i + 1 .. limit], space) {
+                return None;
+            }
+            Some(1)
+        } else if c == b'`' {
+            let (n, beg, _) = self.scan_inline_code(&self.text[i..limit]);
+            Some(if n != 0 { n } else { beg })
+        } else if c == b'<' {
+            let n = self.scan_autolink_or_html(&self.text[i..limit]);
+            Some(if n != 0 { n } else { 1 })
+        } else if c == b'[' {
+            if self.opts.contains(OPTION_ENABLE_FOOTNOTES) {
+                if let Some((_, n)) = self.parse_footnote(&self.text[i..limit]) {
+                    return Some(n);
+                }
+            }
+            match self.parse_link(&self.text[i..limit], false) {
+                Some((_, _, _, n)) => Some(n),
+                None => Some(1)
+            }
+        } else {
+            Some(1)
+        }
+    }
+
     // # Links
 
     // scans a link label, example [link]
@@ -1706,6 +1916,9 @@ impl<'a> Iterator for RawParser<'a> {
     fn next(&mut self) -> Option<Event<'a>> {
         //println!("off {} {:?}, stack {:?} containers {:?}",
         //        self.off, self.state, self.stack, self.containers);
+        if let Some(checked) = self.task_marker.take() {
+            return Some(Event::TaskListMarker(checked));
+        }
         if self.off < self.text.len() {
             match self.state {
                 State::StartBlock | State::InContainers => {
@@ -20,20 +20,44 @@
 
 //! Main public pull parse interface, running two passes over input.
 
-use parse::{RawParser, Event, Tag, Options, OPTION_FIRST_PASS};
-use std::collections::HashSet;
+use parse::{RawParser, Event, Tag, Options, OPTION_FIRST_PASS, OPTION_ENABLE_AUTOLINK,
+    OPTION_ENABLE_EMOJI, EmojiResolver};
+use utils;
+use std::borrow::Cow::Borrowed;
+use std::borrow::Cow;
+use std::collections::{HashSet, VecDeque};
+use links::find_links;
 
 pub struct Parser<'a> {
     inner: RawParser<'a>,
+    opts: Options,
     loose_lists: HashSet<usize>,
     loose_stack: Vec<bool>,
+
+    // events produced ahead of time by the autolink pass, with their offsets
+    pending: VecDeque<(Event<'a>, usize)>,
+    // nesting of links, images and code, in which text isn't autolinked
+    literal_depth: usize,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(text: &'a str) -> Parser<'a> {
         Parser::new_ext(text, Options::empty())
     }
-    pub fn new_ext(text: &'a str, mut opts: Options) -> Parser<'a> {
+    pub fn new_ext(text: &'a str, opts: Options) -> Parser<'a> {
+        Parser::new_with_resolver(text, opts, None)
+    }
+
+    /// Parse `text` with emoji shortcodes enabled, resolving them with
+    /// `resolver` instead of the built-in table in the `emoji` module.
+    /// Shortcodes it returns `None` for are left as text.
+    pub fn new_with_emoji<F>(text: &'a str, opts: Options, resolver: F) -> Parser<'a>
+            where F: Fn(&str) -> Option<String> + 'a {
+        Parser::new_with_resolver(text, opts | OPTION_ENABLE_EMOJI, Some(Box::new(resolver)))
+    }
+
+    fn new_with_resolver(text: &'a str, mut opts: Options,
+            resolver: Option<EmojiResolver<'a>>) -> Parser<'a> {
         opts.remove(OPTION_FIRST_PASS);
         // first pass, collecting info
         let first_opts = opts | OPTION_FIRST_PASS;
@@ -43,25 +67,74 @@ impl<'a> Parser<'a> {
 
         // second pass
         let loose_lists = info.loose_lists;
-        let second = RawParser::new_with_links(text, opts, info.links);
+        let mut second = RawParser::new_with_links(text, opts, info.links);
+        if let Some(resolver) = resolver {
+            second.set_emoji_resolver(resolver);
+        }
 
         //println!("loose lists: {:?}", info.loose_lists);
         Parser {
             inner: second,
+            opts: opts,
             loose_lists: loose_lists,
             loose_stack: Vec::new(),
+            pending: VecDeque::new(),
+            literal_depth: 0,
         }
     }
 
     pub fn get_offset(&self) -> usize {
-        self.inner.get_offset()
+        match self.pending.front() {
+            Some(&(_, offset)) => offset,
+            None => self.inner.get_offset()
+        }
     }
-}
 
-impl<'a> Iterator for Parser<'a> {
-    type Item = Event<'a>;
+    // Split text around the URLs and email addresses in it, wrapping them in links.
+    // `chunks` has the position in `text` and source offset of each piece it was
+    // joined from.
+    fn autolink(&mut self, text: Cow<'a, str>, chunks: &[(usize, usize)]) {
+        let offset = |pos: usize| {
+            let &(start, offset) = chunks.iter().rev().find(|&&(start, _)| start <= pos).unwrap();
+            offset + pos - start
+        };
+        let mut mark = 0;
+        for (start, end, is_email) in find_links(&text) {
+            if start > mark {
+                self.pending.push_back((Event::Text(utils::cow_slice(&text, mark, start)), offset(mark)));
+            }
+            let dest = if is_email {
+                Cow::Owned(format!("mailto:{}", &text[start..end]))
+            } else {
+                utils::cow_slice(&text, start, end)
+            };
+            self.pending.push_back((Event::Start(Tag::Link(dest.clone(), Borrowed(""))), offset(start)));
+            self.pending.push_back((Event::Text(utils::cow_slice(&text, start, end)), offset(start)));
+            self.pending.push_back((Event::End(Tag::Link(dest, Borrowed(""))), offset(end)));
+            mark = end;
+        }
+        if mark < text.len() {
+            self.pending.push_back((Event::Text(utils::cow_slice(&text, mark, text.len())), offset(mark)));
+        }
+    }
 
-    fn next(&mut self) -> Option<Event<'a>> {
+    fn next_event(&mut self) -> Option<Event<'a>> {
+        let event = self.next_block_event();
+        match event {
+            Some(Event::Start(Tag::Link(_, _))) | Some(Event::Start(Tag::Image(_, _))) |
+            Some(Event::Start(Tag::Code)) | Some(Event::Start(Tag::CodeBlock(_))) => {
+                self.literal_depth += 1;
+            }
+            Some(Event::End(Tag::Link(_, _))) | Some(Event::End(Tag::Image(_, _))) |
+            Some(Event::End(Tag::Code)) | Some(Event::End(Tag::CodeBlock(_))) => {
+                self.literal_depth -= 1;
+            }
+            _ => ()
+        }
+        event
+    }
+
+    fn next_block_event(&mut self) -> Option<Event<'a>> {
         loop {
             match self.inner.next() {
                 Some(event) => {
@@ -92,6 +165,41 @@ impl<'a> Iterator for Parser<'a> {
     }
 }
 
+impl<'a> Iterator for Parser<'a> {
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Event<'a>> {
+        if let Some((event, _)) = self.pending.pop_front() {
+            return Some(event);
+        }
+        let offset = self.inner.get_offset();
+        match self.next_event() {
+            Some(Event::Text(text)) => {
+                if !self.opts.contains(OPTION_ENABLE_AUTOLINK) || self.literal_depth > 0 {
+                    return Some(Event::Text(text));
+                }
+                // text is split at markup characters, so join it up before looking for links
+                let mut text = text;
+                let mut chunks = vec![(0, offset)];
+                let (next, next_offset) = loop {
+                    let offset = self.inner.get_offset();
+                    match self.next_event() {
+                        Some(Event::Text(more)) => {
+                            chunks.push((text.len(), offset));
+                            text = utils::cow_append(text, more);
+                        }
+                        next => break (next, offset),
+                    }
+                };
+                self.autolink(text, &chunks);
+                self.pending.extend(next.map(|event| (event, next_offset)));
+                self.pending.pop_front().map(|(event, _)| event)
+            }
+            event => event
+        }
+    }
+}
+
 // Note: there are currently no tests here, because so far there's been adequate coverage
 // using the test cases from the CommonMark spec. Those can be run using:
 //
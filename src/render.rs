@@ -0,0 +1,67 @@
+// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Pieces shared by the renderers.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+// Bullet glyphs for unordered lists, cycled by nesting depth.
+pub const BULLETS: [&'static str; 3] = ["•", "◦", "▪"];
+
+// Footnote numbers, in order of first reference.
+pub type FootnoteNumbers<'a> = HashMap<Cow<'a, str>, usize>;
+
+// The number of the footnote `name`, giving it the next one if it's new.
+pub fn footnote_number<'a>(numbers: &mut FootnoteNumbers<'a>, name: Cow<'a, str>) -> usize {
+    let len = numbers.len() + 1;
+    *numbers.entry(name).or_insert(len)
+}
+
+// Control characters in the source could make up escape sequences of their
+// own, so only tabs are let through to a terminal or chat client.
+pub fn is_printable(c: char) -> bool {
+    c == '\t' || !(c < ' ' || (c >= '\x7f' && c <= '\u{9f}'))
+}
+
+pub fn fresh_line(buf: &mut String) {
+    if !(buf.is_empty() || buf.ends_with('\n')) {
+        buf.push('\n');
+    }
+}
+
+// Separate a block from the one before it with a blank line, unless nothing
+// has been written since `block_start`.
+pub fn blank_line(buf: &mut String, block_start: usize) {
+    if buf.len() == block_start {
+        return;
+    }
+    fresh_line(buf);
+    if !buf.ends_with("\n\n") {
+        buf.push('\n');
+    }
+}
+
+// Drop the trailing whitespace written since `start`, but not what the caller
+// had in the buffer already.
+pub fn trim_end_from(buf: &mut String, start: usize) {
+    let len = start + buf[start..].trim_end().len();
+    buf.truncate(len);
+}
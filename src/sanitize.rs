@@ -0,0 +1,253 @@
+// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Sanitizing of raw HTML before it is written into Pango markup.
+
+use std::collections::HashMap;
+
+use escape::escape_html;
+use scanners::{is_ascii_alpha, is_ascii_alphanumeric, is_ascii_whitespace, scan_while};
+
+/// What to do with raw HTML (`Html` and `InlineHtml` events) in the source.
+#[derive(Clone, Debug)]
+pub enum RawHtml {
+    /// Escape all raw HTML, so that it shows up as literal text.
+    Escape,
+    /// Keep the tags and attributes allowed by the whitelist, escape the rest.
+    Whitelist(Whitelist),
+}
+
+impl Default for RawHtml {
+    fn default() -> RawHtml {
+        RawHtml::Escape
+    }
+}
+
+/// The tags, and the attributes on each of them, that raw HTML may pass
+/// through to the markup.
+///
+/// Pango rejects attribute values it can't parse, so only whitelist
+/// attributes whose values you trust; color attributes are the exception,
+/// as their values are checked to be hex colors.
+#[derive(Clone, Debug, Default)]
+pub struct Whitelist {
+    tags: HashMap<String, Vec<String>>,
+}
+
+impl Whitelist {
+    /// An empty whitelist, which escapes every tag.
+    pub fn new() -> Whitelist {
+        Whitelist::default()
+    }
+
+    /// The formatting tags Pango accepts, plus colored `<span>`s.
+    pub fn pango() -> Whitelist {
+        Whitelist::new()
+            .allow("b", &[])
+            .allow("big", &[])
+            .allow("i", &[])
+            .allow("s", &[])
+            .allow("small", &[])
+            .allow("sub", &[])
+            .allow("sup", &[])
+            .allow("tt", &[])
+            .allow("u", &[])
+            .allow("span", &["foreground", "background"])
+    }
+
+    /// Allow `tag`, with the given attributes. Other attributes are dropped.
+    pub fn allow(mut self, tag: &str, attrs: &[&str]) -> Whitelist {
+        let attrs = attrs.iter().map(|attr| attr.to_lowercase()).collect();
+        self.tags.insert(tag.to_lowercase(), attrs);
+        self
+    }
+
+    fn attrs(&self, tag: &str) -> Option<&Vec<String>> {
+        self.tags.get(tag)
+    }
+}
+
+struct HtmlTag<'a> {
+    name: String,
+    attrs: Vec<(String, &'a str)>,
+    closing: bool,
+    self_closing: bool,
+}
+
+fn is_attr_name_char(c: u8) -> bool {
+    is_ascii_alphanumeric(c) || c == b'_' || c == b':' || c == b'.' || c == b'-'
+}
+
+// Parse a single start or end tag at the beginning of `data`.
+// Returns the tag and the number of bytes it spans.
+fn parse_tag<'a>(data: &'a str) -> Option<(HtmlTag<'a>, usize)> {
+    let bytes = data.as_bytes();
+    let mut i = 1;
+    let closing = bytes.get(i) == Some(&b'/');
+    if closing {
+        i += 1;
+    }
+    if i >= bytes.len() || !is_ascii_alpha(bytes[i]) {
+        return None;
+    }
+    let n = scan_while(&data[i..], is_ascii_alphanumeric);
+    let name = data[i .. i + n].to_lowercase();
+    i += n;
+    let mut attrs = Vec::new();
+    loop {
+        i += scan_while(&data[i..], is_ascii_whitespace);
+        match bytes.get(i) {
+            None => return None,
+            Some(&b'>') => {
+                let tag = HtmlTag { name: name, attrs: attrs, closing: closing, self_closing: false };
+                return Some((tag, i + 1));
+            }
+            Some(&b'/') if bytes.get(i + 1) == Some(&b'>') => {
+                let tag = HtmlTag { name: name, attrs: attrs, closing: closing, self_closing: true };
+                return Some((tag, i + 2));
+            }
+            _ => ()
+        }
+        if closing {
+            return None;
+        }
+        let n = scan_while(&data[i..], is_attr_name_char);
+        if n == 0 {
+            return None;
+        }
+        let attr = data[i .. i + n].to_lowercase();
+        i += n;
+        let mut j = i + scan_while(&data[i..], is_ascii_whitespace);
+        if bytes.get(j) != Some(&b'=') {
+            // attribute without a value
+            attrs.push((attr, ""));
+            continue;
+        }
+        j += 1;
+        j += scan_while(&data[j..], is_ascii_whitespace);
+        let value = match bytes.get(j) {
+            Some(&quote) if quote == b'"' || quote == b'\'' => {
+                let len = match data[j + 1 ..].find(quote as char) {
+                    Some(len) => len,
+                    None => return None
+                };
+                i = j + len + 2;
+                &data[j + 1 .. j + 1 + len]
+            }
+            Some(_) => {
+                let len = scan_while(&data[j..], |c| !is_ascii_whitespace(c) && c != b'>');
+                i = j + len;
+                &data[j .. i]
+            }
+            None => return None
+        };
+        attrs.push((attr, value));
+    }
+}
+
+fn is_color_attr(attr: &str) -> bool {
+    match attr {
+        "foreground" | "fgcolor" | "color" | "background" | "bgcolor" |
+        "underline_color" | "strikethrough_color" => true,
+        _ => false
+    }
+}
+
+// Pango's color names depend on the system, so only accept hex colors.
+fn is_hex_color(value: &str) -> bool {
+    if !value.starts_with('#') {
+        return false;
+    }
+    let digits = &value[1..];
+    [3, 6, 9, 12].contains(&digits.len()) && digits.chars().all(|c| c.is_digit(16))
+}
+
+fn push_tag(buf: &mut String, tag: &HtmlTag, allowed: &[String]) {
+    buf.push('<');
+    buf.push_str(&tag.name);
+    for &(ref attr, value) in &tag.attrs {
+        if !allowed.contains(attr) || value.is_empty() ||
+                (is_color_attr(attr) && !is_hex_color(value)) {
+            continue;
+        }
+        buf.push(' ');
+        buf.push_str(attr);
+        buf.push_str("=\"");
+        escape_html(buf, value, false);
+        buf.push('"');
+    }
+    buf.push('>');
+}
+
+/// Write raw HTML to `buf` according to the whitelist, escaping anything it
+/// doesn't allow. Tags left open are pushed on `open`, so that they can be
+/// closed with `close_tags` later on. Only the tags above `depth` on `open`
+/// can be closed here; closing one of the others would cross our own markup.
+pub fn push_html(buf: &mut String, html: &str, whitelist: &Whitelist, open: &mut Vec<String>,
+        depth: usize) {
+    let mut mark = 0;
+    let mut i = 0;
+    while let Some(pos) = html[i..].find('<') {
+        i += pos;
+        escape_html(buf, &html[mark..i], false);
+        mark = i;
+        let (tag, n) = match parse_tag(&html[i..]) {
+            Some(parsed) => parsed,
+            None => {
+                i += 1;
+                continue;
+            }
+        };
+        let allowed = match whitelist.attrs(&tag.name) {
+            Some(allowed) => allowed,
+            None => {
+                i += n;
+                continue;
+            }
+        };
+        if tag.closing {
+            if open.len() <= depth || open.last() != Some(&tag.name) {
+                i += n;
+                continue;
+            }
+            open.pop();
+            buf.push_str("</");
+            buf.push_str(&tag.name);
+            buf.push('>');
+        } else if !tag.self_closing {
+            // a self-closing tag has no content to format, so just drop it
+            push_tag(buf, &tag, allowed);
+            open.push(tag.name);
+        }
+        i += n;
+        mark = i;
+    }
+    escape_html(buf, &html[mark..], false);
+}
+
+/// Close the tags opened by `push_html`, until only `depth` remain open.
+pub fn close_tags(buf: &mut String, open: &mut Vec<String>, depth: usize) {
+    while open.len() > depth {
+        let name = open.pop().unwrap();
+        buf.push_str("</");
+        buf.push_str(&name);
+        buf.push('>');
+    }
+}
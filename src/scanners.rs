@@ -416,6 +416,69 @@ pub fn scan_listitem(data: &str) -> (usize, u8, usize, usize) {
     (w + postn, c, start, w + postindent)
 }
 
+// scan a GFM task list checkbox at the start of an item, "[ ]" or "[x]",
+// returning its size including the whitespace after it, and whether it's checked
+pub fn scan_task_marker(data: &str) -> Option<(usize, bool)> {
+    let bytes = data.as_bytes();
+    if bytes.len() < 4 || bytes[0] != b'[' || bytes[2] != b']' {
+        return None;
+    }
+    let checked = match bytes[1] {
+        b' ' => false,
+        b'x' | b'X' => true,
+        _ => return None
+    };
+    let n = scan_whitespace_no_nl(&data[3..]);
+    // the checkbox needs text after it
+    if n == 0 || scan_eol(&data[3 + n ..]).1 {
+        return None;
+    }
+    Some((3 + n, checked))
+}
+
+fn is_mention_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-' || c == '.'
+}
+
+// scan the name of an `@user` or `#channel` mention whose sigil is at `loc`,
+// returning its length, or 0 if there's no mention there
+pub fn scan_mention(data: &str, loc: usize) -> usize {
+    // only at the start of a word, and not in paths or entities
+    match scan_trailing_codepoint(&data[..loc]) {
+        Some(c) if c.is_alphanumeric() || c == '@' || c == '#' || c == '/' || c == '&' => return 0,
+        _ => ()
+    }
+    let name = &data[loc + 1 ..];
+    match scan_codepoint(name) {
+        Some(c) if c.is_alphabetic() || c == '_' => (),
+        _ => return 0
+    }
+    let end = name.find(|c| !is_mention_char(c)).unwrap_or(name.len());
+    // punctuation ending a sentence isn't part of the name
+    let n = name[..end].trim_end_matches(|c| c == '.' || c == '-').len();
+    // an email address or a longer handle isn't a mention
+    if name[n..].starts_with('@') {
+        return 0;
+    }
+    n
+}
+
+// scan an emoji shortcode like `:smile:` whose first colon is at `loc`,
+// returning the length of the name between the colons, or 0 if there's none
+pub fn scan_emoji_shortcode(data: &str, loc: usize) -> usize {
+    // not in the middle of a word, or a time like 10:30:00
+    match scan_trailing_codepoint(&data[..loc]) {
+        Some(c) if c.is_alphanumeric() => return 0,
+        _ => ()
+    }
+    let name = &data[loc + 1 ..];
+    let n = scan_while(name, |c| is_ascii_alphanumeric(c) || c == b'_' || c == b'+' || c == b'-');
+    if n == 0 || !name[n..].starts_with(':') {
+        return 0;
+    }
+    n
+}
+
 // return whether delimeter run can open or close
 pub fn compute_open_close(data: &str, loc: usize, c: u8) -> (usize, bool, bool) {
     // TODO: handle Unicode, not just ASCII
@@ -439,7 +502,7 @@ pub fn compute_open_close(data: &str, loc: usize, c: u8) -> (usize, bool, bool)
     let left_flanking = !white_after && (!punc_after || white_before || punc_before);
     let right_flanking = !white_before && (!punc_before || white_after || punc_after);
     let (can_open, can_close) = match c {
-        b'*' => (left_flanking, right_flanking),
+        b'*' | b'~' | b'|' => (left_flanking, right_flanking),
         b'_' => (left_flanking && (!right_flanking || punc_before),
                 right_flanking && (!left_flanking || punc_after)),
         _ => (false, false)
@@ -0,0 +1,454 @@
+// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Renderer that takes an iterator of events as input and produces plain
+//! text with styled ranges, to be applied as Pango attributes or GTK text
+//! tags without going through markup.
+
+use render::{self, BULLETS, FootnoteNumbers};
+use parse::Event::{Start, End, Text, Html, InlineHtml, SoftBreak, HardBreak, FootnoteReference,
+    TaskListMarker, Mention, Emoji};
+use parse::{Event, Tag, MentionKind};
+use pango::SoftBreaks;
+
+/// A style applied to a range of the text.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Style {
+    Bold,
+    Italic,
+    Strikethrough,
+    /// Text to hide until the reader asks for it.
+    Spoiler,
+    Monospace,
+    Superscript,
+    /// A link to the given destination.
+    Link(String),
+    /// A mention of the given user or channel, without the sigil.
+    Mention(MentionKind, String),
+    /// Font size relative to the surrounding text.
+    Scale(f64),
+    /// Text color, as a hex color such as `#888888`.
+    Foreground(String),
+}
+
+/// A style and the byte range of the text it applies to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyledRange {
+    pub start: usize,
+    pub end: usize,
+    pub style: Style,
+}
+
+impl StyledRange {
+    /// The range in characters instead of bytes, as `GtkTextBuffer` counts
+    /// offsets. Pango attributes use byte offsets, as stored.
+    pub fn char_range(&self, text: &str) -> (usize, usize) {
+        let start = text[..self.start].chars().count();
+        (start, start + text[self.start..self.end].chars().count())
+    }
+}
+
+/// Options for `push_styled_with`.
+#[derive(Clone, Debug)]
+pub struct StyledOptions {
+    /// Styles of each heading level, from 1 to 6.
+    pub headings: [Vec<Style>; 6],
+    /// Color of block quote markers and horizontal rules.
+    pub muted: String,
+    /// How soft line breaks are rendered.
+    pub soft_breaks: SoftBreaks,
+    /// Length in characters of the line drawn for a horizontal rule.
+    pub rule_width: usize,
+}
+
+impl Default for StyledOptions {
+    // big and bold for the top levels, down to small text for level 6
+    fn default() -> StyledOptions {
+        StyledOptions {
+            headings: [
+                vec![Style::Scale(1.44), Style::Bold],
+                vec![Style::Scale(1.44)],
+                vec![Style::Scale(1.2), Style::Bold],
+                vec![Style::Bold],
+                vec![Style::Italic],
+                vec![Style::Scale(0.83)],
+            ],
+            muted: "#888888".to_string(),
+            soft_breaks: SoftBreaks::Newline,
+            rule_width: 20,
+        }
+    }
+}
+
+struct Ctx<'a, 'b, 'c, I> {
+    iter: I,
+    buf: &'b mut String,
+    ranges: &'b mut Vec<StyledRange>,
+    opts: &'c StyledOptions,
+    // styles opened by each open tag, with the offsets where they start
+    frames: Vec<Vec<(usize, Style)>>,
+    // next item number for each open list, `None` for bullet lists
+    lists: Vec<Option<usize>>,
+    quotes: usize,
+    // whether the quote markers of the current line are still to be written
+    line_start: bool,
+    // whether the last line written is a blank one
+    blank: bool,
+    // buffer offset at which a block needs no blank line in front of it
+    block_start: usize,
+    // whether the previous event was a piece of an HTML block
+    in_html: bool,
+    // number of open links, as only the outermost one is kept
+    links: usize,
+    first_cell: bool,
+    // whether the leading whitespace of a table cell is still to be skipped
+    trim_cell: bool,
+    cell_start: usize,
+    numbers: FootnoteNumbers<'a>,
+}
+
+impl<'a, 'b, 'c, I: Iterator<Item=Event<'a>>> Ctx<'a, 'b, 'c, I> {
+    // Write the quote markers at the start of a line.
+    fn line_prefix(&mut self) {
+        self.trim_cell = false;
+        self.blank = false;
+        if !self.line_start {
+            return;
+        }
+        self.line_start = false;
+        for _ in 0..self.quotes {
+            let style = Style::Foreground(self.opts.muted.clone());
+            self.push_styled("┃", style);
+            self.buf.push(' ');
+        }
+    }
+
+    fn newline(&mut self) {
+        self.buf.push('\n');
+        self.line_start = true;
+    }
+
+    fn push_text(&mut self, text: &str) {
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.newline();
+            }
+            if !line.is_empty() {
+                self.line_prefix();
+                self.buf.push_str(line);
+            }
+        }
+    }
+
+    fn push_styled(&mut self, text: &str, style: Style) {
+        let start = self.buf.len();
+        self.buf.push_str(text);
+        self.ranges.push(StyledRange { start: start, end: self.buf.len(), style: style });
+    }
+
+    fn fresh_line(&mut self) {
+        if !self.buf.is_empty() && !self.line_start {
+            self.newline();
+        }
+    }
+
+    // Separate a block from the one before it with a blank line.
+    fn blank_line(&mut self) {
+        if self.buf.len() == self.block_start || self.blank {
+            return;
+        }
+        self.fresh_line();
+        // a blank line in a quote is still marked, without the trailing space
+        self.line_prefix();
+        let len = self.buf.trim_end_matches(' ').len();
+        self.buf.truncate(len);
+        self.newline();
+        self.blank = true;
+    }
+
+    // Start `style` at the current offset, ending along with the current tag.
+    fn open(&mut self, style: Style) {
+        self.line_prefix();
+        let start = self.buf.len();
+        if let Some(frame) = self.frames.last_mut() {
+            frame.push((start, style));
+        }
+    }
+
+    fn close_frame(&mut self) {
+        let frame = match self.frames.pop() {
+            Some(frame) => frame,
+            None => return,
+        };
+        // ranges stop before the line break that ends a block
+        let end = self.buf.trim_end_matches('\n').len();
+        for (start, style) in frame {
+            if end > start {
+                self.ranges.push(StyledRange { start: start, end: end, style: style });
+            }
+        }
+    }
+
+    // Drop trailing whitespace back to `start`, shortening the ranges over it.
+    fn trim_end(&mut self, start: usize) {
+        render::trim_end_from(self.buf, start);
+        let len = self.buf.len();
+        for range in self.ranges.iter_mut() {
+            if range.end > len {
+                range.end = len;
+            }
+        }
+        self.ranges.retain(|range| range.start < range.end);
+    }
+
+    pub fn run(&mut self) {
+        let start = self.buf.len();
+        while let Some(event) = self.iter.next() {
+            let is_html = match event {
+                Html(_) => true,
+                _ => false,
+            };
+            match event {
+                Start(tag) => {
+                    self.frames.push(Vec::new());
+                    self.start_tag(tag);
+                }
+                End(tag) => {
+                    self.end_tag(tag);
+                    self.close_frame();
+                }
+                Text(text) => {
+                    let text = if self.trim_cell { text.trim_start() } else { &text[..] };
+                    self.push_text(text);
+                }
+                Html(html) => {
+                    if !self.in_html {
+                        self.blank_line();
+                    }
+                    self.push_text(&html);
+                }
+                InlineHtml(html) => self.push_text(&html),
+                SoftBreak => match self.opts.soft_breaks {
+                    SoftBreaks::Newline => self.newline(),
+                    SoftBreaks::Space => self.buf.push(' '),
+                },
+                HardBreak => self.newline(),
+                FootnoteReference(name) => {
+                    let number = render::footnote_number(&mut self.numbers, name);
+                    self.line_prefix();
+                    self.push_styled(&number.to_string(), Style::Superscript);
+                }
+                TaskListMarker(checked) => {
+                    self.push_text(if checked { "☑ " } else { "☐ " });
+                    self.block_start = self.buf.len();
+                }
+                Mention(kind, name) => {
+                    self.line_prefix();
+                    let text = format!("{}{}", kind.sigil(), name);
+                    self.push_styled(&text, Style::Mention(kind, name.into_owned()));
+                }
+                Emoji(_, emoji) => self.push_text(&emoji),
+            }
+            self.in_html = is_html;
+        }
+        self.trim_end(start);
+        self.ranges.sort_by_key(|range| range.start);
+    }
+
+    fn start_tag(&mut self, tag: Tag<'a>) {
+        match tag {
+            Tag::Paragraph => self.blank_line(),
+            Tag::Header(level) => {
+                self.blank_line();
+                let index = if level < 1 { 0 } else if level > 6 { 5 } else { level as usize - 1 };
+                let opts = self.opts;
+                for style in &opts.headings[index] {
+                    self.open(style.clone());
+                }
+            }
+            Tag::Rule => {
+                self.blank_line();
+                self.line_prefix();
+                let rule: String = (0..self.opts.rule_width).map(|_| '─').collect();
+                let style = Style::Foreground(self.opts.muted.clone());
+                self.push_styled(&rule, style);
+                self.newline();
+            }
+            Tag::CodeBlock(_) => {
+                self.blank_line();
+                self.open(Style::Monospace);
+            }
+            Tag::BlockQuote => {
+                self.blank_line();
+                self.quotes += 1;
+                self.block_start = self.buf.len();
+            }
+            Tag::List(start) => {
+                if self.lists.is_empty() {
+                    self.blank_line();
+                } else {
+                    self.fresh_line();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.fresh_line();
+                self.line_prefix();
+                let depth = self.lists.len();
+                for _ in 1..depth {
+                    self.buf.push_str("  ");
+                }
+                match self.lists.last_mut() {
+                    Some(&mut Some(ref mut number)) => {
+                        self.buf.push_str(&format!("{}. ", number));
+                        *number += 1;
+                    }
+                    _ => {
+                        self.buf.push_str(BULLETS[depth.saturating_sub(1) % BULLETS.len()]);
+                        self.buf.push(' ');
+                    }
+                }
+                self.block_start = self.buf.len();
+            }
+            Tag::Table(_) => self.blank_line(),
+            Tag::TableHead | Tag::TableRow => {
+                self.fresh_line();
+                if tag == Tag::TableHead {
+                    self.open(Style::Bold);
+                }
+                self.first_cell = true;
+            }
+            Tag::TableCell => {
+                self.line_prefix();
+                if !self.first_cell {
+                    self.buf.push_str(" | ");
+                }
+                self.first_cell = false;
+                self.trim_cell = true;
+                self.cell_start = self.buf.len();
+            }
+            Tag::FootnoteDefinition(name) => {
+                self.blank_line();
+                self.open(Style::Scale(0.83));
+                let number = render::footnote_number(&mut self.numbers, name);
+                self.push_styled(&number.to_string(), Style::Superscript);
+                self.buf.push(' ');
+                self.block_start = self.buf.len();
+            }
+            Tag::Emphasis => self.open(Style::Italic),
+            Tag::Strong => self.open(Style::Bold),
+            Tag::Strikethrough => self.open(Style::Strikethrough),
+            Tag::Spoiler => self.open(Style::Spoiler),
+            Tag::Code => self.open(Style::Monospace),
+            // images can't be shown inline, so their alt text links to them
+            Tag::Link(dest, _) | Tag::Image(dest, _) => {
+                self.links += 1;
+                if self.links == 1 {
+                    self.open(Style::Link(dest.into_owned()));
+                }
+            }
+        }
+    }
+
+    fn end_tag(&mut self, tag: Tag) {
+        match tag {
+            Tag::BlockQuote => {
+                self.quotes -= 1;
+                self.fresh_line();
+            }
+            Tag::List(_) => {
+                self.lists.pop();
+                self.fresh_line();
+            }
+            Tag::TableCell => {
+                let start = self.cell_start;
+                self.trim_end(start);
+                self.trim_cell = false;
+            }
+            Tag::Link(dest, _) | Tag::Image(dest, _) => {
+                self.links -= 1;
+                // a link without text shows its destination
+                let empty = match self.frames.last() {
+                    Some(frame) => frame.iter().all(|&(start, _)| start == self.buf.len()),
+                    None => false,
+                };
+                if self.links == 0 && empty {
+                    self.push_text(&dest);
+                }
+            }
+            _ => ()
+        }
+    }
+}
+
+/// Iterate over an `Iterator` of `Event`s, push the text they contain to a
+/// `String`, and the styles of that text to a `Vec` of ranges.
+///
+/// List items get bullets or numbers and quoted lines a marker, like in the
+/// `pango` renderer. Table rows are written as cells separated by ` | `, and
+/// footnote definitions stay where they are in the source, in smaller text.
+/// Ranges are in bytes from the start of `buf`, including anything it held
+/// before, and are sorted by their start. Raw HTML shows up as text.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{styled, Parser};
+/// use pulldown_cmark::styled::{Style, StyledRange};
+///
+/// let mut text = String::new();
+/// let mut ranges = Vec::new();
+/// styled::push_styled(&mut text, &mut ranges, Parser::new("Hello *world*"));
+///
+/// assert_eq!(text, "Hello world");
+/// assert_eq!(ranges, vec![StyledRange { start: 6, end: 11, style: Style::Italic }]);
+/// ```
+pub fn push_styled<'a, I>(buf: &mut String, ranges: &mut Vec<StyledRange>, iter: I)
+    where I: Iterator<Item=Event<'a>>
+{
+    push_styled_with(buf, ranges, iter, &StyledOptions::default());
+}
+
+/// Like `push_styled`, but with control over the styles and layout.
+pub fn push_styled_with<'a, I>(buf: &mut String, ranges: &mut Vec<StyledRange>, iter: I,
+                               opts: &StyledOptions)
+    where I: Iterator<Item=Event<'a>>
+{
+    let start = buf.len();
+    let mut ctx = Ctx {
+        iter: iter,
+        buf: buf,
+        ranges: ranges,
+        opts: opts,
+        frames: Vec::new(),
+        lists: Vec::new(),
+        quotes: 0,
+        line_start: true,
+        blank: false,
+        block_start: start,
+        in_html: false,
+        links: 0,
+        first_cell: true,
+        trim_cell: false,
+        cell_start: 0,
+        numbers: FootnoteNumbers::new(),
+    };
+    ctx.run();
+}
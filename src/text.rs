@@ -0,0 +1,265 @@
+// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Plain text renderer that takes an iterator of events as input, for
+//! notifications and screen readers.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use render::{self, FootnoteNumbers};
+use parse::Event::{Start, End, Text, Html, InlineHtml, SoftBreak, HardBreak, FootnoteReference,
+    TaskListMarker, Mention, Emoji};
+use parse::{Event, Tag};
+
+struct Ctx<'a, 'b, I> {
+    iter: I,
+    buf: &'b mut String,
+    // next item number for each open list, `None` for bullet lists
+    lists: Vec<Option<usize>>,
+    // buffer offset and marker width of each open list item
+    items: Vec<(usize, usize)>,
+    // buffer offsets where the open block quotes begin
+    quotes: Vec<usize>,
+    // buffer offset where the text of each open link begins, and its destination
+    links: Vec<(usize, Cow<'a, str>)>,
+    // buffer offset at which a block needs no blank line in front of it
+    block_start: usize,
+    first_cell: bool,
+    cell_start: usize,
+    numbers: FootnoteNumbers<'a>,
+}
+
+impl<'a, 'b, I: Iterator<Item=Event<'a>>> Ctx<'a, 'b, I> {
+    // Indent every line written since `start` but the first by `width` spaces.
+    fn indent_from(&mut self, start: usize, width: usize, first: bool) {
+        let text = self.buf.split_off(start);
+        let text = text.trim_end_matches('\n');
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.buf.push('\n');
+            }
+            if (i > 0 || first) && !line.is_empty() {
+                for _ in 0..width {
+                    self.buf.push(' ');
+                }
+            }
+            self.buf.push_str(line);
+        }
+        self.buf.push('\n');
+    }
+
+    // Drop the events up to the end of the current tag, consuming the end tag too.
+    fn skip_tag(&mut self) {
+        let mut nest = 0;
+        while let Some(event) = self.iter.next() {
+            match event {
+                Start(_) => nest += 1,
+                End(_) if nest == 0 => break,
+                End(_) => nest -= 1,
+                _ => (),
+            }
+        }
+    }
+
+    pub fn run(&mut self) {
+        let start = self.buf.len();
+        while let Some(event) = self.iter.next() {
+            match event {
+                Start(tag) => self.start_tag(tag),
+                End(tag) => self.end_tag(tag),
+                Text(text) => self.buf.push_str(&text),
+                // raw HTML means nothing to a reader of plain text
+                Html(_) | InlineHtml(_) => (),
+                SoftBreak => self.buf.push(' '),
+                HardBreak => self.buf.push('\n'),
+                FootnoteReference(name) => {
+                    let number = render::footnote_number(&mut self.numbers, name);
+                    let _ = write!(self.buf, "[{}]", number);
+                }
+                // part of the item marker, so the item's lines line up after it
+                TaskListMarker(checked) => {
+                    self.buf.push_str(if checked { "☑ " } else { "☐ " });
+                    if let Some(&mut (_, ref mut width)) = self.items.last_mut() {
+                        *width += 2;
+                    }
+                    self.block_start = self.buf.len();
+                }
+                Mention(kind, name) => {
+                    self.buf.push(kind.sigil());
+                    self.buf.push_str(&name);
+                }
+                Emoji(_, emoji) => self.buf.push_str(&emoji),
+            }
+        }
+        render::trim_end_from(self.buf, start);
+    }
+
+    fn start_tag(&mut self, tag: Tag<'a>) {
+        match tag {
+            Tag::Paragraph | Tag::Header(_) | Tag::CodeBlock(_) | Tag::Table(_) => {
+                render::blank_line(self.buf, self.block_start);
+            }
+            Tag::Rule => {
+                render::blank_line(self.buf, self.block_start);
+                self.buf.push_str("---\n");
+            }
+            Tag::BlockQuote => {
+                render::blank_line(self.buf, self.block_start);
+                let start = self.buf.len();
+                self.quotes.push(start);
+                self.block_start = start;
+            }
+            Tag::List(start) => {
+                if self.items.is_empty() {
+                    render::blank_line(self.buf, self.block_start);
+                } else {
+                    render::fresh_line(self.buf);
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                render::fresh_line(self.buf);
+                let start = self.buf.len();
+                match self.lists.last_mut() {
+                    Some(&mut Some(ref mut number)) => {
+                        let _ = write!(self.buf, "{}. ", number);
+                        *number += 1;
+                    }
+                    _ => self.buf.push_str("• "),
+                }
+                let width = self.buf[start..].chars().count();
+                self.items.push((start, width));
+                self.block_start = self.buf.len();
+            }
+            Tag::TableHead | Tag::TableRow => {
+                render::fresh_line(self.buf);
+                self.first_cell = true;
+            }
+            Tag::TableCell => {
+                if !self.first_cell {
+                    self.buf.push_str(" | ");
+                }
+                self.first_cell = false;
+                self.cell_start = self.buf.len();
+            }
+            Tag::FootnoteDefinition(name) => {
+                render::blank_line(self.buf, self.block_start);
+                let number = render::footnote_number(&mut self.numbers, name);
+                let _ = write!(self.buf, "[{}] ", number);
+                self.block_start = self.buf.len();
+            }
+            Tag::Link(dest, _) => {
+                let start = self.buf.len();
+                self.links.push((start, dest));
+            }
+            // a reader shouldn't be shown what the spoiler hides
+            Tag::Spoiler => {
+                self.skip_tag();
+                self.buf.push_str("[spoiler]");
+            }
+            Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Code | Tag::Image(_, _) => (),
+        }
+    }
+
+    fn end_tag(&mut self, tag: Tag) {
+        match tag {
+            Tag::Header(_) | Tag::TableHead | Tag::TableRow => self.buf.push('\n'),
+            Tag::TableCell => {
+                let cell = self.buf.split_off(self.cell_start);
+                self.buf.push_str(cell.trim());
+            }
+            Tag::BlockQuote => {
+                render::fresh_line(self.buf);
+                if let Some(start) = self.quotes.pop() {
+                    self.indent_from(start, 2, true);
+                }
+            }
+            Tag::List(_) => {
+                self.lists.pop();
+                render::fresh_line(self.buf);
+            }
+            Tag::Item => {
+                if let Some((start, width)) = self.items.pop() {
+                    self.indent_from(start, width, false);
+                }
+            }
+            Tag::Link(_, _) => {
+                if let Some((start, dest)) = self.links.pop() {
+                    let is_dest = {
+                        let text = &self.buf[start..];
+                        text == dest || (dest.starts_with("mailto:") && text == &dest[7..])
+                    };
+                    if start == self.buf.len() {
+                        self.buf.push_str(&dest);
+                    } else if !is_dest {
+                        let _ = write!(self.buf, " ({})", dest);
+                    }
+                }
+            }
+            _ => ()
+        }
+    }
+}
+
+/// Iterate over an `Iterator` of `Event`s, and push the text they contain to
+/// a `String`, without any markup.
+///
+/// Links are followed by their destination in parentheses, list items are
+/// marked with bullets or numbers, and block quotes are indented. Raw HTML
+/// is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{text, Parser};
+///
+/// let markdown_str = r#"
+/// Read *the* [manual](https://example.com/manual) &amp; then:
+///
+/// 1. alpha
+/// 2. beta
+/// "#;
+/// let parser = Parser::new(markdown_str);
+///
+/// let mut text_buf = String::new();
+/// text::push_text(&mut text_buf, parser);
+///
+/// assert_eq!(text_buf, "Read the manual (https://example.com/manual) & then:
+///
+/// 1. alpha
+/// 2. beta");
+/// ```
+pub fn push_text<'a, I: Iterator<Item=Event<'a>>>(buf: &mut String, iter: I) {
+    let start = buf.len();
+    let mut ctx = Ctx {
+        iter: iter,
+        buf: buf,
+        lists: Vec::new(),
+        items: Vec::new(),
+        quotes: Vec::new(),
+        links: Vec::new(),
+        block_start: start,
+        first_cell: true,
+        cell_start: 0,
+        numbers: FootnoteNumbers::new(),
+    };
+    ctx.run();
+}
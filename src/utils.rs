@@ -22,7 +22,7 @@
 
 use std::cmp;
 use std::borrow::Cow;
-use std::borrow::Cow::Owned;
+use std::borrow::Cow::{Borrowed, Owned};
 
 fn ascii_tolower(c: u8) -> u8 {
     match c {
@@ -51,3 +51,10 @@ pub fn cow_append<'a>(a: Cow<'a, str>, b: Cow<'a, str>) -> Cow<'a, str> {
         Owned(a.into_owned() + &b)
     }
 }
+
+pub fn cow_slice<'a>(s: &Cow<'a, str>, start: usize, end: usize) -> Cow<'a, str> {
+    match *s {
+        Borrowed(s) => Borrowed(&s[start..end]),
+        Owned(ref s) => Owned(s[start..end].to_string()),
+    }
+}
@@ -0,0 +1,421 @@
+// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! XML renderer that takes an iterator of events as input, following the
+//! CommonMark DTD.
+
+use std::fmt::Write;
+
+use escape::escape_html;
+use parse::Event::{Start, End, Text, Html, InlineHtml, SoftBreak, HardBreak, FootnoteReference,
+    TaskListMarker, Mention, Emoji};
+use parse::{Alignment, Event, Tag, MentionKind};
+
+const PRESERVE: (&'static str, &'static str) = ("xml:space", "preserve");
+
+// A list whose start tag hasn't been written yet, as tightness is only known
+// at its end.
+struct List {
+    start: Option<usize>,
+    // where the start tag goes in the buffer
+    offset: usize,
+    tight: bool,
+}
+
+struct Ctx<'b, I> {
+    iter: I,
+    buf: &'b mut String,
+    // names of the open elements
+    open: Vec<&'static str>,
+    lists: Vec<List>,
+    // whether the start tag of the innermost element still needs its `>`,
+    // so that it can become an empty element instead
+    start_open: bool,
+    // text or raw HTML still to be written, as one element per run of events
+    pending: Option<(&'static str, String)>,
+    alignments: Vec<Alignment>,
+    cell: usize,
+}
+
+impl<'a, 'b, I: Iterator<Item=Event<'a>>> Ctx<'b, I> {
+    fn indent(&mut self) {
+        for _ in 0..self.open.len() {
+            self.buf.push_str("  ");
+        }
+    }
+
+    fn close_start(&mut self) {
+        if self.start_open {
+            self.buf.push_str(">\n");
+            self.start_open = false;
+        }
+    }
+
+    fn push_start(&mut self, name: &str, attrs: &[(&str, &str)]) {
+        self.close_start();
+        self.indent();
+        self.buf.push('<');
+        self.buf.push_str(name);
+        for &(attr, value) in attrs {
+            let _ = write!(self.buf, " {}=\"", attr);
+            escape_html(self.buf, value, false);
+            self.buf.push('"');
+        }
+    }
+
+    fn start(&mut self, name: &'static str, attrs: &[(&str, &str)]) {
+        self.push_start(name, attrs);
+        self.start_open = true;
+        self.open.push(name);
+    }
+
+    fn end(&mut self) {
+        let name = self.open.pop().unwrap_or("");
+        if self.start_open {
+            self.buf.push_str(" />\n");
+            self.start_open = false;
+        } else {
+            self.indent();
+            let _ = write!(self.buf, "</{}>\n", name);
+        }
+    }
+
+    // An element without children, with `content` as its literal text if any.
+    fn leaf(&mut self, name: &str, attrs: &[(&str, &str)], content: Option<&str>) {
+        self.push_start(name, attrs);
+        match content {
+            Some(content) => {
+                self.buf.push('>');
+                escape_html(self.buf, content, false);
+                let _ = write!(self.buf, "</{}>\n", name);
+            }
+            None => self.buf.push_str(" />\n"),
+        }
+    }
+
+    fn add_pending(&mut self, name: &'static str, text: &str) {
+        if let Some((pending_name, ref mut pending)) = self.pending {
+            if pending_name == name {
+                pending.push_str(text);
+                return;
+            }
+        }
+        self.flush();
+        self.pending = Some((name, text.to_string()));
+    }
+
+    fn flush(&mut self) {
+        if let Some((name, text)) = self.pending.take() {
+            self.leaf(name, &[PRESERVE], Some(&text));
+        }
+    }
+
+    // Tight list items have no paragraphs in the events, but the DTD wants
+    // inline content in one.
+    fn start_inline(&mut self) {
+        if self.open.last() == Some(&"item") {
+            self.start("paragraph", &[]);
+        }
+    }
+
+    fn start_block(&mut self) {
+        self.flush();
+        if self.open.last() == Some(&"paragraph") {
+            self.end();
+        }
+    }
+
+    pub fn run(&mut self) {
+        self.buf.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        self.buf.push_str("<!DOCTYPE document SYSTEM \"CommonMark.dtd\">\n");
+        self.start("document", &[("xmlns", "http://commonmark.org/xml/1.0")]);
+        while let Some(event) = self.iter.next() {
+            match event {
+                Start(tag) => self.start_tag(tag),
+                End(tag) => self.end_tag(tag),
+                Text(text) => {
+                    self.start_inline();
+                    self.add_pending("text", &text);
+                }
+                Html(html) => {
+                    if self.open.last() == Some(&"paragraph") {
+                        self.start_block();
+                    }
+                    self.add_pending("html_block", &html);
+                }
+                InlineHtml(html) => {
+                    self.flush();
+                    self.start_inline();
+                    self.leaf("html_inline", &[PRESERVE], Some(&html));
+                }
+                SoftBreak => {
+                    self.flush();
+                    self.start_inline();
+                    self.leaf("softbreak", &[], None);
+                }
+                HardBreak => {
+                    self.flush();
+                    self.start_inline();
+                    self.leaf("linebreak", &[], None);
+                }
+                FootnoteReference(name) => {
+                    self.flush();
+                    self.start_inline();
+                    self.leaf("footnote_reference", &[("label", &name)], None);
+                }
+                // an attribute of the item, which comes right before it, as
+                // cmark-gfm writes it
+                TaskListMarker(checked) => {
+                    if self.start_open && self.open.last() == Some(&"item") {
+                        let _ = write!(self.buf, " completed=\"{}\"", checked);
+                    }
+                }
+                Mention(kind, name) => {
+                    self.flush();
+                    self.start_inline();
+                    let kind = match kind {
+                        MentionKind::User => "user",
+                        MentionKind::Channel => "channel",
+                    };
+                    self.leaf("mention", &[("type", kind), ("name", &name)], None);
+                }
+                Emoji(shortcode, emoji) => {
+                    self.flush();
+                    self.start_inline();
+                    self.leaf("emoji", &[("shortcode", &shortcode)], Some(&emoji));
+                }
+            }
+        }
+        self.flush();
+        self.end();
+    }
+
+    fn start_tag(&mut self, tag: Tag<'a>) {
+        match tag {
+            Tag::Paragraph => {
+                self.start_block();
+                if self.open.last() == Some(&"item") {
+                    if let Some(list) = self.lists.last_mut() {
+                        list.tight = false;
+                    }
+                }
+                self.start("paragraph", &[]);
+            }
+            Tag::Rule => {
+                self.start_block();
+                self.leaf("thematic_break", &[], None);
+            }
+            Tag::Header(level) => {
+                self.start_block();
+                self.start("heading", &[("level", &level.to_string())]);
+            }
+            Tag::Table(alignments) => {
+                self.start_block();
+                self.alignments = alignments;
+                self.start("table", &[]);
+            }
+            Tag::TableHead => {
+                self.cell = 0;
+                self.start("table_header", &[]);
+            }
+            Tag::TableRow => {
+                self.cell = 0;
+                self.start("table_row", &[]);
+            }
+            Tag::TableCell => {
+                let align = match self.alignments.get(self.cell) {
+                    Some(&Alignment::Left) => "left",
+                    Some(&Alignment::Center) => "center",
+                    Some(&Alignment::Right) => "right",
+                    _ => "",
+                };
+                self.cell += 1;
+                if align.is_empty() {
+                    self.start("table_cell", &[]);
+                } else {
+                    self.start("table_cell", &[("align", align)]);
+                }
+            }
+            Tag::BlockQuote => {
+                self.start_block();
+                self.start("block_quote", &[]);
+            }
+            Tag::CodeBlock(info) => {
+                self.start_block();
+                let code = self.collect_text();
+                if info.is_empty() {
+                    self.leaf("code_block", &[PRESERVE], Some(&code));
+                } else {
+                    self.leaf("code_block", &[("info", &info), PRESERVE], Some(&code));
+                }
+            }
+            Tag::List(start) => {
+                self.start_block();
+                self.close_start();
+                let offset = self.buf.len();
+                self.lists.push(List { start: start, offset: offset, tight: true });
+                self.open.push("list");
+            }
+            Tag::Item => {
+                self.start_block();
+                self.start("item", &[]);
+            }
+            Tag::FootnoteDefinition(name) => {
+                self.start_block();
+                self.start("footnote_definition", &[("label", &name)]);
+            }
+            Tag::Emphasis => {
+                self.flush();
+                self.start_inline();
+                self.start("emph", &[]);
+            }
+            Tag::Strong => {
+                self.flush();
+                self.start_inline();
+                self.start("strong", &[]);
+            }
+            Tag::Strikethrough => {
+                self.flush();
+                self.start_inline();
+                self.start("strikethrough", &[]);
+            }
+            Tag::Spoiler => {
+                self.flush();
+                self.start_inline();
+                self.start("spoiler", &[]);
+            }
+            Tag::Code => {
+                self.flush();
+                self.start_inline();
+                let code = self.collect_text();
+                self.leaf("code", &[PRESERVE], Some(&code));
+            }
+            Tag::Link(dest, title) => {
+                self.flush();
+                self.start_inline();
+                self.start("link", &[("destination", &dest), ("title", &title)]);
+            }
+            Tag::Image(dest, title) => {
+                self.flush();
+                self.start_inline();
+                self.start("image", &[("destination", &dest), ("title", &title)]);
+            }
+        }
+    }
+
+    fn end_tag(&mut self, tag: Tag) {
+        self.flush();
+        match tag {
+            Tag::Rule => (),
+            Tag::List(_) => {
+                self.open.pop();
+                let list = match self.lists.pop() {
+                    Some(list) => list,
+                    None => return,
+                };
+                let mut start = String::new();
+                for _ in 0..self.open.len() {
+                    start.push_str("  ");
+                }
+                match list.start {
+                    Some(number) => {
+                        let _ = write!(start, "<list type=\"ordered\" start=\"{}\"", number);
+                    }
+                    None => start.push_str("<list type=\"bullet\""),
+                }
+                let _ = write!(start, " tight=\"{}\">\n", list.tight);
+                self.buf.insert_str(list.offset, &start);
+                self.indent();
+                self.buf.push_str("</list>\n");
+            }
+            Tag::Item => {
+                if self.open.last() == Some(&"paragraph") {
+                    self.end();
+                }
+                self.end();
+            }
+            _ => self.end(),
+        }
+    }
+
+    // The text of a code block or span, consuming its end tag.
+    fn collect_text(&mut self) -> String {
+        let mut text = String::new();
+        while let Some(event) = self.iter.next() {
+            match event {
+                Text(more) => text.push_str(&more),
+                End(_) => break,
+                _ => (),
+            }
+        }
+        text
+    }
+}
+
+/// Iterate over an `Iterator` of `Event`s, generate XML for each `Event`, and
+/// push it to a `String`.
+///
+/// The output follows the [CommonMark DTD](https://github.com/commonmark/commonmark-spec/blob/master/CommonMark.dtd),
+/// as written by the reference implementation, which makes it suitable for
+/// comparing document structure. Tables and footnotes, which the DTD doesn't
+/// cover, are written the way cmark-gfm does. Ordered lists have no `delim`
+/// attribute, as the events don't record it.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{xml, Parser};
+///
+/// let mut xml_buf = String::new();
+/// xml::push_xml(&mut xml_buf, Parser::new("* alpha\n* *beta*\n"));
+///
+/// assert_eq!(xml_buf, r#"<?xml version="1.0" encoding="UTF-8"?>
+/// <!DOCTYPE document SYSTEM "CommonMark.dtd">
+/// <document xmlns="http://commonmark.org/xml/1.0">
+///   <list type="bullet" tight="true">
+///     <item>
+///       <paragraph>
+///         <text xml:space="preserve">alpha</text>
+///       </paragraph>
+///     </item>
+///     <item>
+///       <paragraph>
+///         <emph>
+///           <text xml:space="preserve">beta</text>
+///         </emph>
+///       </paragraph>
+///     </item>
+///   </list>
+/// </document>
+/// "#);
+/// ```
+pub fn push_xml<'a, I: Iterator<Item=Event<'a>>>(buf: &mut String, iter: I) {
+    let mut ctx = Ctx {
+        iter: iter,
+        buf: buf,
+        open: Vec::new(),
+        lists: Vec::new(),
+        start_open: false,
+        pending: None,
+        alignments: Vec::new(),
+        cell: 0,
+    };
+    ctx.run();
+}
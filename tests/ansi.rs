@@ -0,0 +1,111 @@
+// Tests for the ANSI terminal renderer.
+
+extern crate pulldown_cmark;
+
+#[test]
+fn ansi_test_1() {
+    let original = r##"# Title
+
+*Emphasis*, **strong** and `code`.
+"##;
+    let expected = "\x1b[1;4mTitle\x1b[0m\n\n\x1b[3mEmphasis\x1b[0m, \x1b[1mstrong\x1b[0m and \x1b[36mcode\x1b[0m.\n";
+
+    use pulldown_cmark::{Parser, ansi};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    ansi::push_ansi(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn ansi_test_2() {
+    let original = r##"See [the docs](https://example.com).
+"##;
+    let expected = "See \x1b[4;34m\x1b]8;;https://example.com\x1b\\the docs\x1b]8;;\x1b\\\x1b[0m.\n";
+
+    use pulldown_cmark::{Parser, ansi};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    ansi::push_ansi(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn ansi_test_3() {
+    let original = r##"> A quote with **bold
+> words** in it.
+"##;
+    let expected = "\x1b[0;2m│\x1b[0m A quote\n\x1b[0;2m│\x1b[0m with \x1b[1mbold\n\x1b[0;2m│\x1b[0m \x1b[1mwords\x1b[0m in\n\x1b[0;2m│\x1b[0m it.\n";
+
+    use pulldown_cmark::{Parser, ansi};
+    use pulldown_cmark::ansi::AnsiOptions;
+
+    let mut s = String::new();
+
+    let opts = AnsiOptions { width: Some(12), hyperlinks: false };
+    let p = Parser::new(&original);
+    ansi::push_ansi_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn ansi_test_4() {
+    let original = r##"- a list item that wraps
+- short
+
+```
+no wrapping in code blocks
+```
+"##;
+    let expected = "• a list item\n  that wraps\n• short\n\n\x1b[36m  no wrapping in code blocks\n\x1b[0m";
+
+    use pulldown_cmark::{Parser, ansi};
+    use pulldown_cmark::ansi::AnsiOptions;
+
+    let mut s = String::new();
+
+    let opts = AnsiOptions { width: Some(14), hyperlinks: false };
+    let p = Parser::new(&original);
+    ansi::push_ansi_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn ansi_test_5() {
+    let original = "hi \x1b[2J\x1b]0;pwned\x07 and `\x1b[31mred` \u{9b}x\n\n```\n\x1b[2Jcode\tblock\n```\n";
+    let expected = "hi [2J]0;pwned and \x1b[36m[31mred\x1b[0m x\n\n\x1b[36m  [2Jcode\tblock\n\x1b[0m";
+
+    use pulldown_cmark::{Parser, ansi};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    ansi::push_ansi(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn ansi_test_6() {
+    let original = "[link](http://a\x1b\\b\x1b]0;pwned\x07)\n";
+    let expected = "\x1b[4;34m\x1b]8;;http://ab]0;pwned\x1b\\link\x1b]8;;\x1b\\\x1b[0m\n";
+
+    use pulldown_cmark::{Parser, ansi};
+    use pulldown_cmark::ansi::AnsiOptions;
+
+    let mut s = String::new();
+
+    let opts = AnsiOptions { width: None, hyperlinks: true };
+    let p = Parser::new(&original);
+    ansi::push_ansi_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
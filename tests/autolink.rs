@@ -0,0 +1,105 @@
+extern crate pulldown_cmark;
+
+use std::borrow::Cow::Borrowed;
+
+use pulldown_cmark::{Parser, Event, Tag, Options, OPTION_ENABLE_AUTOLINK};
+
+fn events(markdown: &str, opts: Options) -> Vec<Event> {
+    Parser::new_ext(markdown, opts).collect()
+}
+
+fn link(dest: &'static str) -> Tag<'static> {
+    Tag::Link(Borrowed(dest), Borrowed(""))
+}
+
+#[test]
+fn test_autolink_disabled_by_default() {
+    assert_eq!(events("see https://example.com", Options::empty()), vec![
+        Event::Start(Tag::Paragraph),
+        Event::Text(Borrowed("see https://example.com")),
+        Event::End(Tag::Paragraph),
+    ]);
+}
+
+#[test]
+fn test_autolink_url() {
+    assert_eq!(events("see https://example.com, ok", OPTION_ENABLE_AUTOLINK), vec![
+        Event::Start(Tag::Paragraph),
+        Event::Text(Borrowed("see ")),
+        Event::Start(link("https://example.com")),
+        Event::Text(Borrowed("https://example.com")),
+        Event::End(link("https://example.com")),
+        Event::Text(Borrowed(", ok")),
+        Event::End(Tag::Paragraph),
+    ]);
+}
+
+#[test]
+fn test_autolink_url_with_ampersand() {
+    let url = "https://example.com/?a=1&b=2";
+    assert_eq!(events("https://example.com/?a=1&b=2", OPTION_ENABLE_AUTOLINK), vec![
+        Event::Start(Tag::Paragraph),
+        Event::Start(link(url)),
+        Event::Text(Borrowed(url)),
+        Event::End(link(url)),
+        Event::End(Tag::Paragraph),
+    ]);
+}
+
+#[test]
+fn test_autolink_email() {
+    assert_eq!(events("mail me@example.com", OPTION_ENABLE_AUTOLINK), vec![
+        Event::Start(Tag::Paragraph),
+        Event::Text(Borrowed("mail ")),
+        Event::Start(link("mailto:me@example.com")),
+        Event::Text(Borrowed("me@example.com")),
+        Event::End(link("mailto:me@example.com")),
+        Event::End(Tag::Paragraph),
+    ]);
+}
+
+#[test]
+fn test_autolink_skips_code_and_links() {
+    let markdown = "`https://a.example` [https://b.example](https://c.example)";
+    assert_eq!(events(markdown, OPTION_ENABLE_AUTOLINK), vec![
+        Event::Start(Tag::Paragraph),
+        Event::Start(Tag::Code),
+        Event::Text(Borrowed("https://a.example")),
+        Event::End(Tag::Code),
+        Event::Text(Borrowed(" ")),
+        Event::Start(link("https://c.example")),
+        Event::Text(Borrowed("https://b.example")),
+        Event::End(link("https://c.example")),
+        Event::End(Tag::Paragraph),
+    ]);
+}
+
+#[test]
+fn test_autolink_offsets() {
+    let markdown = "see https://example.com, *or* me@example.com\n";
+    let mut p = Parser::new_ext(markdown, OPTION_ENABLE_AUTOLINK);
+    let mut offsets = Vec::new();
+    loop {
+        let offset = p.get_offset();
+        match p.next() {
+            Some(event) => offsets.push((offset, event)),
+            None => break,
+        }
+    }
+    assert_eq!(offsets, vec![
+        (0, Event::Start(Tag::Paragraph)),
+        (0, Event::Text(Borrowed("see "))),
+        (4, Event::Start(link("https://example.com"))),
+        (4, Event::Text(Borrowed("https://example.com"))),
+        (23, Event::End(link("https://example.com"))),
+        (23, Event::Text(Borrowed(", "))),
+        (25, Event::Start(Tag::Emphasis)),
+        (26, Event::Text(Borrowed("or"))),
+        (28, Event::End(Tag::Emphasis)),
+        (29, Event::Text(Borrowed(" "))),
+        (30, Event::Start(link("mailto:me@example.com"))),
+        (30, Event::Text(Borrowed("me@example.com"))),
+        (44, Event::End(link("mailto:me@example.com"))),
+        (44, Event::End(Tag::Paragraph)),
+    ]);
+}
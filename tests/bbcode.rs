@@ -0,0 +1,109 @@
+// Tests for the BBCode renderer.
+
+extern crate pulldown_cmark;
+
+#[test]
+fn bbcode_test_1() {
+    let original = r##"# Title
+
+Some *emphasis*, **strong**, `code`, <https://example.com>
+and a [link](https://example.com/a[1] "title").
+
+> quoted
+>
+> ![alt text](https://example.com/img.png)
+"##;
+    let expected = r##"[b]Title[/b]
+
+Some [i]emphasis[/i], [b]strong[/b], `code`, [url]https://example.com[/url] and a [url=https://example.com/a%5B1%5D]link[/url].
+
+[quote]quoted
+
+[img]https://example.com/img.png[/img][/quote]"##;
+
+    use pulldown_cmark::{Parser, bbcode};
+
+    let mut s = String::new();
+    bbcode::push_bbcode(&mut s, Parser::new(original));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn bbcode_test_2() {
+    let original = r##"3. three
+4. four
+   - nested
+
+```rust
+fn main() {}
+```
+
+| a | b |
+|---|---|
+| c | d |
+"##;
+    let expected = r##"[list=1]
+[*]three
+[*]four
+[list]
+[*]nested
+[/list]
+[/list]
+
+[code]fn main() {}[/code]
+
+[b]a | b[/b]
+c | d"##;
+
+    use pulldown_cmark::{Parser, bbcode, OPTION_ENABLE_TABLES};
+
+    let mut s = String::new();
+    bbcode::push_bbcode(&mut s, Parser::new_ext(original, OPTION_ENABLE_TABLES));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn bbcode_test_3() {
+    let original = r##"<!-- nothing to show -->
+"##;
+    let expected = "Summary: ";
+
+    use pulldown_cmark::{Parser, bbcode};
+
+    let mut s = String::from("Summary: ");
+    bbcode::push_bbcode(&mut s, Parser::new(original));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn bbcode_test_4() {
+    let original = r##"```
+[code]x[/code] [/CODE] [b]
+```
+"##;
+    let expected = "[code][code]x[/\u{200b}code] [/\u{200b}CODE] [b][/code]";
+
+    use pulldown_cmark::{Parser, bbcode};
+
+    let mut s = String::new();
+    bbcode::push_bbcode(&mut s, Parser::new(original));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn bbcode_test_5() {
+    let original = r##"> [/quote] [url=javascript:x]a[/url] [img]b[/img]
+>
+> ||[/spoiler]|| and `[b]` but [1] and [ ]
+"##;
+    let expected = "[quote][\u{200b}/quote] [\u{200b}url=javascript:x]a[\u{200b}/url] \
+[\u{200b}img]b[\u{200b}/img]
+
+[spoiler][\u{200b}/spoiler][/spoiler] and `[\u{200b}b]` but [1] and [ ][/quote]";
+
+    use pulldown_cmark::{Parser, bbcode, OPTION_ENABLE_SPOILERS};
+
+    let mut s = String::new();
+    bbcode::push_bbcode(&mut s, Parser::new_ext(original, OPTION_ENABLE_SPOILERS));
+    assert_eq!(expected, s);
+}
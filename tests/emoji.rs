@@ -0,0 +1,273 @@
+// This file is auto-generated by the build script
+// Please, do not modify it manually
+
+extern crate pulldown_cmark;
+
+
+    #[test]
+    fn emoji_test_1() {
+        let original = r##"I :rocket: Rust :tada:
+"##;
+        let expected = r##"<p>I 🚀 Rust 🎉</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn emoji_test_2() {
+        let original = r##":+1: and :-1:
+"##;
+        let expected = r##"<p>👍 and 👎</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn emoji_test_3() {
+        let original = r##":smile::wink:
+"##;
+        let expected = r##"<p>😄😉</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn emoji_test_4() {
+        let original = r##":no_such_emoji: and :smile
+"##;
+        let expected = r##"<p>:no_such_emoji: and :smile</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn emoji_test_5() {
+        let original = r##":__1: isn't :-1:
+"##;
+        let expected = r##"<p>:__1: isn't 👎</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn emoji_test_6() {
+        let original = r##"a:smile: at 10:30:00
+"##;
+        let expected = r##"<p>a:smile: at 10:30:00</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn emoji_test_7() {
+        let original = r##": smile: and :smile :
+"##;
+        let expected = r##"<p>: smile: and :smile :</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn emoji_test_8() {
+        let original = r##"`:smile:` and
+
+    :smile:
+"##;
+        let expected = r##"<p><code>:smile:</code> and</p>
+<pre><code>:smile:
+</code></pre>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn emoji_test_9() {
+        let original = r##"*:smile:* and [:tada:](/party)
+"##;
+        let expected = r##"<p><em>😄</em> and <a href="/party">🎉</a></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn emoji_test_10() {
+        let original = r##"![:cat:](/cat.png)
+"##;
+        let expected = r##"<p><img src="/cat.png" alt="🐱" /></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn emoji_test_11() {
+        let original = r##"<http://example.com/:smile:>
+"##;
+        let expected = r##"<p><a href="http://example.com/:smile:">http://example.com/:smile:</a></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn emoji_test_12() {
+        let original = r##"\:smile:
+"##;
+        let expected = r##"<p>:smile:</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
\ No newline at end of file
@@ -0,0 +1,51 @@
+// This file is auto-generated by the build script
+// Please, do not modify it manually
+
+extern crate pulldown_cmark;
+
+
+    #[test]
+    fn emoji_autolink_test_1() {
+        let original = r##"see https://example.com/:smile:/x
+"##;
+        let expected = r##"<p>see <a href="https://example.com/:smile:/x">https://example.com/:smile:/x</a></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI, OPTION_ENABLE_AUTOLINK};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+        opts.insert(OPTION_ENABLE_AUTOLINK);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn emoji_autolink_test_2() {
+        let original = r##"https://example.com/ :smile: and :tada:https://example.com/
+"##;
+        let expected = r##"<p><a href="https://example.com/">https://example.com/</a> 😄 and 🎉<a href="https://example.com/">https://example.com/</a></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_EMOJI, OPTION_ENABLE_AUTOLINK};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_EMOJI);
+        opts.insert(OPTION_ENABLE_AUTOLINK);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
\ No newline at end of file
@@ -0,0 +1,64 @@
+extern crate pulldown_cmark;
+
+use std::borrow::Cow::{Borrowed, Owned};
+
+use pulldown_cmark::{Parser, Event, Tag, Options, html};
+use pulldown_cmark::emoji::get_emoji;
+
+#[test]
+fn test_resolver_replaces_builtin_table() {
+    // smile is in the built-in table, but not in this one
+    let p = Parser::new_with_emoji(":party: :smile: :tada:", Options::empty(), |shortcode| {
+        match shortcode {
+            "party" | "tada" => Some(shortcode.to_string()),
+            _ => None,
+        }
+    });
+    assert_eq!(p.collect::<Vec<_>>(), vec![
+        Event::Start(Tag::Paragraph),
+        Event::Emoji(Borrowed("party"), Owned("party".to_string())),
+        Event::Text(Borrowed(" ")),
+        Event::Text(Borrowed(":smile")),
+        Event::Text(Borrowed(": ")),
+        Event::Emoji(Borrowed("tada"), Owned("tada".to_string())),
+        Event::End(Tag::Paragraph),
+    ]);
+}
+
+#[test]
+fn test_resolver_falls_back_on_builtin_table() {
+    let p = Parser::new_with_emoji(":party: :smile:", Options::empty(), |shortcode| {
+        match shortcode {
+            "party" => Some("🥳".to_string()),
+            _ => get_emoji(shortcode).map(String::from),
+        }
+    });
+    assert_eq!(p.collect::<Vec<_>>(), vec![
+        Event::Start(Tag::Paragraph),
+        Event::Emoji(Borrowed("party"), Owned("🥳".to_string())),
+        Event::Text(Borrowed(" ")),
+        Event::Emoji(Borrowed("smile"), Owned("😄".to_string())),
+        Event::End(Tag::Paragraph),
+    ]);
+}
+
+#[test]
+fn test_resolved_emoji_as_images() {
+    let p = Parser::new_with_emoji(":party: :smile: :tada:", Options::empty(), |shortcode| {
+        match shortcode {
+            "party" | "tada" => Some(shortcode.to_string()),
+            _ => None,
+        }
+    });
+    let p = p.map(|event| match event {
+        Event::Emoji(ref shortcode, _) if shortcode == "party" => {
+            Event::InlineHtml(Owned(format!(
+                "<img alt=\"{0}\" src=\"/emoji/{0}.png\" />", shortcode)))
+        }
+        Event::Emoji(_, _) => Event::Text(Borrowed("🎉")),
+        event => event,
+    });
+    let mut s = String::new();
+    html::push_html(&mut s, p);
+    assert_eq!(s, "<p><img alt=\"party\" src=\"/emoji/party.png\" /> :smile: 🎉</p>\n");
+}
@@ -0,0 +1,110 @@
+// Tests for the IRC renderer.
+
+extern crate pulldown_cmark;
+
+#[test]
+fn irc_test_1() {
+    let original = r##"# Title
+
+Some *emphasis*, **strong *both***,
+`code` and a [link](https://example.com).
+"##;
+    let expected = "\x02Title\x02\nSome \x1demphasis\x1d, \x02strong \x1dboth\x1d\x02, \x11code\x11 and a link (https://example.com).";
+
+    use pulldown_cmark::{Parser, irc};
+
+    let mut s = String::new();
+    irc::push_irc(&mut s, Parser::new(original));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn irc_test_2() {
+    let original = r##"> *quoted
+> text*\
+> more
+
+```
+line one
+line two
+```
+"##;
+    let expected = "\x0314>\x03 \x1dquoted text\x1d\n\x0314>\x03 more\n\x11line one\x0f\n\x11line two\x0f";
+
+    use pulldown_cmark::{Parser, irc};
+
+    let mut s = String::new();
+    irc::push_irc(&mut s, Parser::new(original));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn irc_test_3() {
+    let original = "* <https://example.com>\n* no \x02control\x03 codes\n";
+    let expected = "• https://example.com\n• no control codes";
+
+    use pulldown_cmark::{Parser, irc};
+
+    let mut s = String::new();
+    irc::push_irc(&mut s, Parser::new(original));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn irc_test_4() {
+    let original = r##"**bold
+across lines**
+"##;
+    let expected = "\x02bold\x0f\n\x02across lines\x02";
+
+    use pulldown_cmark::{Parser, irc};
+
+    let mut s = String::new();
+    irc::push_irc(&mut s, Parser::new(original).map(|event| match event {
+        pulldown_cmark::Event::SoftBreak => pulldown_cmark::Event::HardBreak,
+        event => event,
+    }));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn irc_test_5() {
+    let original = r##"||spoiler
+across lines||2
+"##;
+    let expected = "\x0301,01spoiler\x0f\n\x0301,01across lines\x03\x02\x022";
+
+    use pulldown_cmark::{Parser, irc, OPTION_ENABLE_SPOILERS};
+
+    let mut s = String::new();
+    irc::push_irc(&mut s, Parser::new_ext(original, OPTION_ENABLE_SPOILERS).map(|event| match event {
+        pulldown_cmark::Event::SoftBreak => pulldown_cmark::Event::HardBreak,
+        event => event,
+    }));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn irc_test_6() {
+    let original = r##"<!-- nothing to show -->
+"##;
+    let expected = "Summary: ";
+
+    use pulldown_cmark::{Parser, irc};
+
+    let mut s = String::from("Summary: ");
+    irc::push_irc(&mut s, Parser::new(original));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn irc_test_7() {
+    let original = "no \x7fdelete, \u{85}next line or \u{9b}31m escapes\n";
+    let expected = "no delete, next line or 31m escapes";
+
+    use pulldown_cmark::{Parser, irc};
+
+    let mut s = String::new();
+    irc::push_irc(&mut s, Parser::new(original));
+    assert_eq!(expected, s);
+}
@@ -0,0 +1,407 @@
+// Tests for the Markdown writer: parsing its output must give back the same events.
+
+extern crate pulldown_cmark;
+
+use pulldown_cmark::{Parser, Event, Options, markdown};
+use pulldown_cmark::{OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_STRIKETHROUGH,
+    OPTION_ENABLE_TASKLISTS, OPTION_ENABLE_MENTIONS, OPTION_ENABLE_EMOJI,
+    OPTION_ENABLE_SPOILERS};
+use pulldown_cmark::markdown::{HeadingStyle, LinkStyle, MarkdownOptions};
+
+// The events for `text`, with adjacent text events joined, since escaping
+// can split text up differently.
+fn events(text: &str, opts: Options) -> Vec<Event> {
+    let mut events: Vec<Event> = Vec::new();
+    for event in Parser::new_ext(text, opts) {
+        if let Event::Text(ref text) = event {
+            if let Some(&mut Event::Text(ref mut last)) = events.last_mut() {
+                *last = format!("{}{}", last, text).into();
+                continue;
+            }
+        }
+        events.push(event);
+    }
+    events
+}
+
+fn round_trip(original: &str, opts: Options) -> String {
+    round_trip_with(original, opts, &MarkdownOptions::default())
+}
+
+fn round_trip_with(original: &str, opts: Options, markdown_opts: &MarkdownOptions) -> String {
+    let mut s = String::new();
+    markdown::push_markdown_with(&mut s, Parser::new_ext(original, opts), markdown_opts);
+    assert_eq!(events(original, opts), events(&s, opts), "markdown was:\n{}", s);
+    s
+}
+
+#[test]
+fn markdown_test_1() {
+    let original = r##"Setext heading
+==============
+
+Some *emphasis*, __strong__ and `code`,
+with a hard break\
+and a [link](/url "title") and ![image](/img.png).
+
+* * *
+
+> quoted
+> > nested
+"##;
+    let expected = r##"# Setext heading
+
+Some *emphasis*, **strong** and `code`,
+with a hard break\
+and a [link](/url "title") and ![image](/img.png).
+
+***
+
+> quoted
+>
+> > nested
+"##;
+
+    assert_eq!(expected, round_trip(original, Options::empty()));
+}
+
+#[test]
+fn markdown_test_2() {
+    let original = r##"1. one
+2. two
+   + nested
+   + tight
+
+Between.
+
+- loose
+
+- list
+
+  with paragraphs
+"##;
+    let expected = r##"1. one
+2. two
+   - nested
+   - tight
+
+Between.
+
+- loose
+
+- list
+
+  with paragraphs
+"##;
+
+    assert_eq!(expected, round_trip(original, Options::empty()));
+}
+
+#[test]
+fn markdown_test_3() {
+    let original = r##"~~~ rust
+let s = "```";
+```
+~~~
+
+Code with `` `backticks` `` in it.
+"##;
+    let expected = r##"````rust
+let s = "```";
+```
+````
+
+Code with `` `backticks` `` in it.
+"##;
+
+    assert_eq!(expected, round_trip(original, Options::empty()));
+}
+
+#[test]
+fn markdown_test_4() {
+    let original = r##"\# not a heading, \*not emphasis\*, \[not a link\](x)
+
+1\. not a list
+\- nor this \+ one
+
+\<b\> &amp;copy; 5 \> 3 &lt; 4 a_b_c
+"##;
+    round_trip(original, Options::empty());
+}
+
+#[test]
+fn markdown_test_5() {
+    let original = r##"- a
+- b
+
+1) c
+
++ d
+* e
+"##;
+    round_trip(original, Options::empty());
+}
+
+#[test]
+fn markdown_test_6() {
+    let original = r##"| Name | Pipe \| in it |
+|:-----|-------------:|
+| a    | *b*          |
+
+Text[^note] <https://example.com> <user@example.com>.
+
+[^note]: The note.
+"##;
+    let expected = r##"| Name | Pipe \| in it |
+|:--|--:|
+| a    | *b*          |
+
+Text[^note] <https://example.com> <user@example.com>.
+
+[^note]: The note.
+"##;
+
+    assert_eq!(expected, round_trip(original, OPTION_ENABLE_TABLES | OPTION_ENABLE_FOOTNOTES));
+}
+
+#[test]
+fn markdown_test_7() {
+    let original = r##"<div>
+*raw*
+</div>
+
+***strong emphasis*** and *emphasis with **strong***
+"##;
+    round_trip(original, Options::empty());
+}
+
+#[test]
+fn markdown_test_8() {
+    let original = r##"# Title
+
+Some *emphasis*, **strong**, *nested **strong***, and intra*word*.
+
+## Section
+
+### Deeper
+
+- a
+- b
+"##;
+    let expected = r##"Title
+=====
+
+Some _emphasis_, __strong__, _nested **strong**_, and intra*word*.
+
+Section
+-------
+
+### Deeper
+
++ a
++ b
+"##;
+
+    let markdown_opts = MarkdownOptions {
+        emphasis: '_',
+        bullet: '+',
+        headings: HeadingStyle::Setext,
+        ..MarkdownOptions::default()
+    };
+    assert_eq!(expected, round_trip_with(original, Options::empty(), &markdown_opts));
+}
+
+#[test]
+fn markdown_test_9() {
+    let original = r##"A [link](/url "title"), the [same](/url "title") again,
+and ![an image](/img.png).
+
+> [Another](/other) and <https://example.com>.
+"##;
+    let expected = r##"A [link][1], the [same][1] again,
+and ![an image][2].
+
+[1]: /url "title"
+[2]: /img.png
+
+> [Another][3] and <https://example.com>.
+
+[3]: /other
+"##;
+
+    let markdown_opts = MarkdownOptions { links: LinkStyle::AfterBlock, ..MarkdownOptions::default() };
+    assert_eq!(expected, round_trip_with(original, Options::empty(), &markdown_opts));
+}
+
+#[test]
+fn markdown_test_10() {
+    let original = r##"A [link](/url) here.
+
+And [another](/other).
+"##;
+    let expected = r##"A [link][1] here.
+
+And [another][2].
+
+[1]: /url
+[2]: /other
+"##;
+
+    let markdown_opts = MarkdownOptions { links: LinkStyle::AtEnd, ..MarkdownOptions::default() };
+    assert_eq!(expected, round_trip_with(original, Options::empty(), &markdown_opts));
+}
+
+#[test]
+fn markdown_test_11() {
+    let original = r##"A paragraph with quite a few words in it,
+which gets wrapped at twenty columns: 1. - # not block markup.
+
+- an item that also wraps
+> # Heading that stays on one line
+"##;
+    let expected = r##"A paragraph with
+quite a few words in
+it, which gets
+wrapped at twenty
+columns: 1. - # not
+block markup.
+
+- an item that also
+  wraps
+
+> # Heading that stays on one line
+"##;
+
+    let mut s = String::new();
+    let markdown_opts = MarkdownOptions { wrap: Some(20), ..MarkdownOptions::default() };
+    markdown::push_markdown_with(&mut s, Parser::new(original), &markdown_opts);
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn markdown_test_12() {
+    let original = r##"~struck~ with *~~emphasis~~*, and a ~ tilde
+
+~~~
+fenced
+~~~
+"##;
+    let expected = r##"~~struck~~ with *~~emphasis~~*, and a \~ tilde
+
+```
+fenced
+```
+"##;
+
+    assert_eq!(expected, round_trip(original, OPTION_ENABLE_STRIKETHROUGH));
+}
+
+#[test]
+fn markdown_test_13() {
+    let original = r##"* [X] done
+* [ ]   todo
+  * [ ] nested
+* [ ]literal
+"##;
+    let expected = r##"- [x] done
+- [ ] todo
+  - [ ] nested
+- \[ \]literal
+"##;
+
+    assert_eq!(expected, round_trip(original, OPTION_ENABLE_TASKLISTS));
+}
+
+#[test]
+fn markdown_test_14() {
+    let original = r##"Ask @bob in #help, not \@alice or `#code`.
+
+Mail bob@example.com about C# at &#35;dev.
+"##;
+    let expected = r##"Ask @bob in #help, not \@alice or `#code`.
+
+Mail bob@example.com about C# at \#dev.
+"##;
+
+    assert_eq!(expected, round_trip(original, OPTION_ENABLE_MENTIONS));
+    round_trip(original, Options::empty());
+}
+
+#[test]
+fn markdown_test_15() {
+    let original = r##"Ship it :rocket::tada: but not \:no_such_emoji: or `:smile:`.
+
+(:smile\:) and \:smile: at 10:30:00
+"##;
+    let expected = r##"Ship it :rocket::tada: but not \:no\_such\_emoji: or `:smile:`.
+
+(\:smile:) and \:smile: at 10:30:00
+"##;
+
+    assert_eq!(expected, round_trip(original, OPTION_ENABLE_EMOJI));
+    round_trip(original, Options::empty());
+}
+
+#[test]
+fn markdown_test_16() {
+    let original = r##"It was ||*the butler*|| all along, a \|\|red herring\|\| or a | b.
+
+| a | b |
+| - | - |
+| \|\|c\|\| | d |
+"##;
+    let expected = r##"It was ||*the butler*|| all along, a \|\|red herring\|\| or a | b.
+
+| a | b |
+|---|---|
+| \|\|c\|\| | d |
+"##;
+
+    assert_eq!(expected, round_trip(original, OPTION_ENABLE_SPOILERS | OPTION_ENABLE_TABLES));
+    round_trip(original, OPTION_ENABLE_TABLES);
+}
+
+#[test]
+fn markdown_test_17() {
+    let original = r##"***a*b**, x***y*z** and ***a** b*
+"##;
+    let expected = r##"***a*b**, x***y*z** and *__a__ b*
+"##;
+
+    assert_eq!(expected, round_trip(original, Options::empty()));
+    let markdown_opts = MarkdownOptions { emphasis: '_', ..MarkdownOptions::default() };
+    round_trip_with(original, Options::empty(), &markdown_opts);
+}
+
+#[test]
+fn markdown_test_18() {
+    let original = r##"- foo
+- ***
+- bar
+
+***
+"##;
+    let expected = r##"* foo
+* ___
+* bar
+
+***
+"##;
+
+    let markdown_opts = MarkdownOptions { bullet: '*', ..MarkdownOptions::default() };
+    assert_eq!(expected, round_trip_with(original, Options::empty(), &markdown_opts));
+}
+
+#[test]
+fn markdown_test_19() {
+    let original = r##"- [ ] # h
+- [x] > q
+- [ ] ---
+"##;
+    let expected = r##"- \[ \] # h
+- \[x\] > q
+- \[ \] ---
+"##;
+
+    assert_eq!(expected, round_trip(original, OPTION_ENABLE_TASKLISTS));
+}
@@ -0,0 +1,57 @@
+// Tests for the Matrix HTML renderer.
+
+extern crate pulldown_cmark;
+
+#[test]
+fn matrix_test_1() {
+    let original = r##"Hello <span data-mx-color="#ff0000">there</span>
+
+<script>alert(1)</script>
+
+[safe](https://example.com "title") [unsafe](javascript:alert(1)) [relative](/path)
+"##;
+    let expected = r##"<p>Hello &lt;span data-mx-color=&quot;#ff0000&quot;&gt;there&lt;/span&gt;</p>
+<p>&lt;script&gt;alert(1)&lt;/script&gt;</p>
+<p><a href="https://example.com" title="title">safe</a> unsafe relative</p>
+"##;
+
+    use pulldown_cmark::{Parser, matrix};
+
+    let mut s = String::new();
+    matrix::push_matrix(&mut s, Parser::new(original));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn matrix_test_2() {
+    let original = r##"![avatar](mxc://example.org/abc "Avatar") ![remote](https://example.org/a.png)
+
+| a | b |
+|---|---|
+| c | d |
+"##;
+    let expected = r##"<p><img src="mxc://example.org/abc" alt="avatar" title="Avatar" /> <a href="https://example.org/a.png">remote</a></p>
+<table><thead><tr><th> a </th><th> b </th></tr></thead>
+<tr><td> c </td><td> d </td></tr>
+</table>
+"##;
+
+    use pulldown_cmark::{Parser, matrix, OPTION_ENABLE_TABLES};
+
+    let mut s = String::new();
+    matrix::push_matrix(&mut s, Parser::new_ext(original, OPTION_ENABLE_TABLES));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn matrix_test_3() {
+    use pulldown_cmark::{Parser, matrix};
+
+    let original = format!("{}*deep*\n", "> ".repeat(120));
+    let mut s = String::new();
+    matrix::push_matrix(&mut s, Parser::new(&original));
+    assert_eq!(matrix::MAX_DEPTH, s.matches("<blockquote>").count());
+    assert_eq!(matrix::MAX_DEPTH, s.matches("</blockquote>").count());
+    assert!(!s.contains("<em>"));
+    assert!(s.contains("deep"));
+}
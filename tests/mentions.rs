@@ -0,0 +1,316 @@
+// This file is auto-generated by the build script
+// Please, do not modify it manually
+
+extern crate pulldown_cmark;
+
+
+    #[test]
+    fn mentions_test_1() {
+        let original = r##"Hi @bob, see #general.
+"##;
+        let expected = r##"<p>Hi <span class="user-mention">@bob</span>, see <span class="channel-mention">#general</span>.</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_2() {
+        let original = r##"mail bob@example.com, C#, a/@b and &@c
+"##;
+        let expected = r##"<p>mail bob@example.com, C#, a/@b and &amp;@c</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_3() {
+        let original = r##"@_under, @bob-smith, @a.b and #dev-ops
+"##;
+        let expected = r##"<p><span class="user-mention">@_under</span>, <span class="user-mention">@bob-smith</span>, <span class="user-mention">@a.b</span> and <span class="channel-mention">#dev-ops</span></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_4() {
+        let original = r##"Thanks @bob. Ask #help-.
+"##;
+        let expected = r##"<p>Thanks <span class="user-mention">@bob</span>. Ask <span class="channel-mention">#help</span>-.</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_5() {
+        let original = r##"@1digit, #1 and @ alone
+"##;
+        let expected = r##"<p>@1digit, #1 and @ alone</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_6() {
+        let original = r##"@bob@example.social
+"##;
+        let expected = r##"<p>@bob@example.social</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_7() {
+        let original = r##"@zoë and #日本
+"##;
+        let expected = r##"<p><span class="user-mention">@zoë</span> and <span class="channel-mention">#日本</span></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_8() {
+        let original = r##"*@bob* and _#general_
+"##;
+        let expected = r##"<p><em><span class="user-mention">@bob</span></em> and <em><span class="channel-mention">#general</span></em></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_9() {
+        let original = r##"\@bob and \#general
+"##;
+        let expected = r##"<p>@bob and #general</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_10() {
+        let original = r##"`@bob` and `#general`
+"##;
+        let expected = r##"<p><code>@bob</code> and <code>#general</code></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_11() {
+        let original = r##"<https://example.com/@bob> <https://example.com/#general>
+"##;
+        let expected = r##"<p><a href="https://example.com/@bob">https://example.com/@bob</a> <a href="https://example.com/#general">https://example.com/#general</a></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_12() {
+        let original = r##"[@bob](/users/bob) and [text](/#general)
+"##;
+        let expected = r##"<p><a href="/users/bob">@bob</a> and <a href="/#general">text</a></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_13() {
+        let original = r##"#general
+
+# Heading #general
+"##;
+        let expected = r##"<p><span class="channel-mention">#general</span></p>
+<h1>Heading <span class="channel-mention">#general</span></h1>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_test_14() {
+        let original = r##"&#64;bob and &#35;general
+"##;
+        let expected = r##"<p>@bob and #general</p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
\ No newline at end of file
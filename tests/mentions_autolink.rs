@@ -0,0 +1,97 @@
+// This file is auto-generated by the build script
+// Please, do not modify it manually
+
+extern crate pulldown_cmark;
+
+
+    #[test]
+    fn mentions_autolink_test_1() {
+        let original = r##"see https://example.com/a?b=@carol and https://example.com/?#intro
+"##;
+        let expected = r##"<p>see <a href="https://example.com/a?b=@carol">https://example.com/a?b=@carol</a> and <a href="https://example.com/?#intro">https://example.com/?#intro</a></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS, OPTION_ENABLE_AUTOLINK};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+        opts.insert(OPTION_ENABLE_AUTOLINK);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_autolink_test_2() {
+        let original = r##"**https://example.com/#top**, then @bob in #general
+"##;
+        let expected = r##"<p><strong><a href="https://example.com/#top">https://example.com/#top</a></strong>, then <span class="user-mention">@bob</span> in <span class="channel-mention">#general</span></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS, OPTION_ENABLE_AUTOLINK};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+        opts.insert(OPTION_ENABLE_AUTOLINK);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_autolink_test_3() {
+        let original = r##"mail bob@example.com or @bob@example.social
+"##;
+        let expected = r##"<p>mail <a href="mailto:bob@example.com">bob@example.com</a> or @<a href="mailto:bob@example.social">bob@example.social</a></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS, OPTION_ENABLE_AUTOLINK};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+        opts.insert(OPTION_ENABLE_AUTOLINK);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn mentions_autolink_test_4() {
+        let original = r##"(@bob)https://example.com/ and https://example.com/ @bob
+"##;
+        let expected = r##"<p>(<span class="user-mention">@bob</span>)<a href="https://example.com/">https://example.com/</a> and <a href="https://example.com/">https://example.com/</a> <span class="user-mention">@bob</span></p>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_MENTIONS, OPTION_ENABLE_AUTOLINK};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_MENTIONS);
+        opts.insert(OPTION_ENABLE_AUTOLINK);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
\ No newline at end of file
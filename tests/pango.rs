@@ -0,0 +1,601 @@
+// Tests for the Pango markup renderer.
+
+extern crate pulldown_cmark;
+
+#[test]
+fn test_bullet_list() {
+    let original = r##"* alpha
+* beta
+"##;
+    let expected = r##"• alpha
+• beta
+"##;
+
+    use pulldown_cmark::{Parser, pango};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_ordered_list_start() {
+    let original = r##"3. three
+4. four
+"##;
+    let expected = r##"3. three
+4. four
+"##;
+
+    use pulldown_cmark::{Parser, pango};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_nested_list() {
+    let original = r##"* alpha
+  1. one
+  2. two
+     * deep
+* beta
+"##;
+    let expected = r##"• alpha
+  1. one
+  2. two
+    ▪ deep
+• beta
+"##;
+
+    use pulldown_cmark::{Parser, pango};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_blockquote() {
+    let original = r##"> quoted
+> reply
+"##;
+    let expected = r##"<span foreground="#888888">┃</span> quoted
+<span foreground="#888888">┃</span> reply
+"##;
+
+    use pulldown_cmark::{Parser, pango};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_nested_blockquote() {
+    let original = r##"> outer
+>
+> > inner
+"##;
+    let expected = r##"<span foreground="#888888">┃</span> outer
+<span foreground="#888888">┃</span> <span foreground="#888888">┃</span> inner
+"##;
+
+    use pulldown_cmark::{Parser, pango};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_raw_html_escaped_by_default() {
+    let original = r##"<span size="100000">huge</span> <b>bold"##;
+    let expected = r##"&lt;span size=&quot;100000&quot;&gt;huge&lt;/span&gt; &lt;b&gt;bold"##;
+
+    use pulldown_cmark::{Parser, pango};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_raw_html_block_escaped_by_default() {
+    let original = r##"<div>
+<script>alert(1)</script>
+</div>
+"##;
+    let expected = r##"&lt;div&gt;
+&lt;script&gt;alert(1)&lt;/script&gt;
+&lt;/div&gt;
+"##;
+
+    use pulldown_cmark::{Parser, pango};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_raw_html_whitelist() {
+    let original = r##"<b>bold</b> <marquee>no</marquee> <span foreground="#ff0000" size="100000">red</span>"##;
+    let expected = r##"<b>bold</b> &lt;marquee&gt;no&lt;/marquee&gt; <span foreground="#ff0000">red</span>"##;
+
+    use pulldown_cmark::{Parser, pango};
+    use pulldown_cmark::pango::{RenderOptions, RawHtml, Whitelist};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    let opts = RenderOptions { raw_html: RawHtml::Whitelist(Whitelist::pango()), ..RenderOptions::default() };
+    pango::push_html_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_raw_html_whitelist_rejects_bad_colors() {
+    let original = r##"<span foreground="blurple" background="#00f">text</span>"##;
+    let expected = r##"<span background="#00f">text</span>"##;
+
+    use pulldown_cmark::{Parser, pango};
+    use pulldown_cmark::pango::{RenderOptions, RawHtml, Whitelist};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    let opts = RenderOptions { raw_html: RawHtml::Whitelist(Whitelist::pango()), ..RenderOptions::default() };
+    pango::push_html_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_raw_html_whitelist_balances_tags() {
+    let original = r##"*<b>unclosed* text</i> </b>"##;
+    let expected = r##"<i><b>unclosed</b></i> text&lt;/i&gt; &lt;/b&gt;"##;
+
+    use pulldown_cmark::{Parser, pango};
+    use pulldown_cmark::pango::{RenderOptions, RawHtml, Whitelist};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    let opts = RenderOptions { raw_html: RawHtml::Whitelist(Whitelist::pango()), ..RenderOptions::default() };
+    pango::push_html_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_raw_html_whitelist_keeps_tags_inside_emphasis() {
+    let original = r##"<b>a *b</b> c*"##;
+    let expected = r##"<b>a <i>b&lt;/b&gt; c</i></b>"##;
+
+    use pulldown_cmark::{Parser, pango};
+    use pulldown_cmark::pango::{RenderOptions, RawHtml, Whitelist};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    let opts = RenderOptions { raw_html: RawHtml::Whitelist(Whitelist::pango()), ..RenderOptions::default() };
+    pango::push_html_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_raw_html_custom_whitelist() {
+    let original = r##"<u>under</u> <b>bold</b>"##;
+    let expected = r##"<u>under</u> &lt;b&gt;bold&lt;/b&gt;"##;
+
+    use pulldown_cmark::{Parser, pango};
+    use pulldown_cmark::pango::{RenderOptions, RawHtml, Whitelist};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    let opts = RenderOptions { raw_html: RawHtml::Whitelist(Whitelist::new().allow("u", &[])), ..RenderOptions::default() };
+    pango::push_html_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_heading_levels() {
+    let original = r##"# one
+
+### three
+
+###### six
+"##;
+    let expected = r##"<big><big><b>one</b></big></big>
+<big><b>three</b></big>
+<span font_variant="smallcaps">six</span>"##;
+
+    use pulldown_cmark::{Parser, pango};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_custom_heading_styles() {
+    let original = r##"Title
+=====
+
+Subtitle
+--------
+"##;
+    let expected = r##"<span size="x-large">Title</span>
+<big><big>Subtitle</big></big>"##;
+
+    use pulldown_cmark::{Parser, pango};
+    use pulldown_cmark::pango::{RenderOptions, HeadingStyles};
+
+    let mut s = String::new();
+
+    let opts = RenderOptions {
+        headings: HeadingStyles::default().level(1, "<span size=\"x-large\">", "</span>"),
+        ..RenderOptions::default()
+    };
+    let p = Parser::new(&original);
+    pango::push_html_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_monospace_font() {
+    let original = r##"`inline`
+
+    block
+"##;
+    let expected = r##"<span font_family="Source Code Pro">inline</span>
+<span font_family="Source Code Pro">block
+</span>
+"##;
+
+    use pulldown_cmark::{Parser, pango};
+    use pulldown_cmark::pango::RenderOptions;
+
+    let mut s = String::new();
+
+    let opts = RenderOptions { monospace_font: Some("Source Code Pro".to_string()), ..RenderOptions::default() };
+    let p = Parser::new(&original);
+    pango::push_html_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_quote_marker() {
+    let original = r##"> quoted
+"##;
+    let expected = r##"| quoted
+"##;
+
+    use pulldown_cmark::{Parser, pango};
+    use pulldown_cmark::pango::RenderOptions;
+
+    let mut s = String::new();
+
+    let opts = RenderOptions { quote_marker: "| ".to_string(), ..RenderOptions::default() };
+    let p = Parser::new(&original);
+    pango::push_html_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_soft_breaks_as_spaces() {
+    let original = r##"one
+two\
+three
+"##;
+    let expected = r##"one two
+three"##;
+
+    use pulldown_cmark::{Parser, pango};
+    use pulldown_cmark::pango::{RenderOptions, SoftBreaks};
+
+    let mut s = String::new();
+
+    let opts = RenderOptions { soft_breaks: SoftBreaks::Space, ..RenderOptions::default() };
+    let p = Parser::new(&original);
+    pango::push_html_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_autolink() {
+    let original = r##"see https://example.com/?a=1&b=2"##;
+    let expected = r##"see <a href="https://example.com/?a=1&amp;b=2">https://example.com/?a=1&amp;b=2</a>"##;
+
+    use pulldown_cmark::{Parser, pango, OPTION_ENABLE_AUTOLINK};
+
+    let mut s = String::new();
+
+    let p = Parser::new_ext(&original, OPTION_ENABLE_AUTOLINK);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_table() {
+    let original = r##"| Name | Qty | Note |
+|:-----|----:|:----:|
+| apple | 3 | *ripe* |
+| fig | 12 |
+"##;
+    let expected = r##"<tt>┌───────┬─────┬──────┐
+│ <b>Name</b>  │ <b>Qty</b> │ <b>Note</b> │
+├───────┼─────┼──────┤
+│ apple │   3 │ <i>ripe</i> │
+│ fig   │  12 │      │
+└───────┴─────┴──────┘</tt>
+"##;
+
+    use pulldown_cmark::{Parser, pango, OPTION_ENABLE_TABLES};
+
+    let mut s = String::new();
+
+    let p = Parser::new_ext(&original, OPTION_ENABLE_TABLES);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_table_wide_characters() {
+    let original = r##"| Word | Meaning |
+|------|---------|
+| 日本 | Japan |
+| 🎉 | party |
+"##;
+    let expected = r##"<tt>┌──────┬─────────┐
+│ <b>Word</b> │ <b>Meaning</b> │
+├──────┼─────────┤
+│ 日本 │ Japan   │
+│ 🎉   │ party   │
+└──────┴─────────┘</tt>
+"##;
+
+    use pulldown_cmark::{Parser, pango, OPTION_ENABLE_TABLES};
+
+    let mut s = String::new();
+
+    let p = Parser::new_ext(&original, OPTION_ENABLE_TABLES);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_table_center_and_entities() {
+    let original = r##"a|b
+:-:|-
+x & y|z
+"##;
+    let expected = r##"<tt>┌───────┬───┐
+│   <b>a</b>   │ <b>b</b> │
+├───────┼───┤
+│ x &amp; y │ z │
+└───────┴───┘</tt>
+"##;
+
+    use pulldown_cmark::{Parser, pango, OPTION_ENABLE_TABLES};
+
+    let mut s = String::new();
+
+    let p = Parser::new_ext(&original, OPTION_ENABLE_TABLES);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_footnotes() {
+    let original = r##"Lorem[^b] ipsum[^a] dolor[^b].
+
+[^a]: First *cited*.
+
+[^b]: Cited twice.
+"##;
+    let expected = r##"Lorem<sup>1</sup> ipsum<sup>2</sup> dolor<sup>1</sup>.
+<small><sup>1</sup> Cited twice.
+<sup>2</sup> First <i>cited</i>.</small>
+"##;
+
+    use pulldown_cmark::{Parser, pango, OPTION_ENABLE_FOOTNOTES};
+
+    let mut s = String::new();
+
+    let p = Parser::new_ext(&original, OPTION_ENABLE_FOOTNOTES);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_unreferenced_footnote() {
+    let original = r##"Text[^used].
+
+[^unused]: Never cited.
+
+[^used]: Cited.
+"##;
+    let expected = r##"Text<sup>1</sup>.
+<small><sup>1</sup> Cited.
+<sup>2</sup> Never cited.</small>
+"##;
+
+    use pulldown_cmark::{Parser, pango, OPTION_ENABLE_FOOTNOTES};
+
+    let mut s = String::new();
+
+    let p = Parser::new_ext(&original, OPTION_ENABLE_FOOTNOTES);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_image_alt_link() {
+    let original = r##"See ![a *small* cat](cat.png "Felix").
+"##;
+    let expected = r##"See <a href="cat.png" title="Felix">a <i>small</i> cat</a>."##;
+
+    use pulldown_cmark::{Parser, pango};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_image_in_link() {
+    let original = r##"[![logo](logo.png)](https://example.com)
+"##;
+    let expected = r##"<a href="https://example.com">logo</a>"##;
+
+    use pulldown_cmark::{Parser, pango};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_image_placeholder() {
+    let original = r##"Before ![a *small* cat](cat.png) after ![dog](dog.png "Rex").
+"##;
+    let expected = r##"Before <span foreground="#888888">[image]</span> after <span foreground="#888888">[image]</span>."##;
+
+    use pulldown_cmark::{Parser, pango};
+    use pulldown_cmark::pango::{Images, RenderOptions};
+
+    let mut s = String::new();
+    let mut images = Vec::new();
+
+    let opts = RenderOptions {
+        images: Images::Placeholder("<span foreground=\"#888888\">[image]</span>".to_string()),
+        ..RenderOptions::default()
+    };
+    let p = Parser::new(&original);
+    pango::push_html_with_images(&mut s, p, &opts, |dest, title| {
+        images.push(format!("{} {}", dest, title));
+    });
+
+    assert_eq!(expected, s);
+    assert_eq!(vec!["cat.png ", "dog.png Rex"], images);
+}
+
+#[test]
+fn test_rule() {
+    let original = r##"Above
+***
+Below
+"##;
+    let expected = r##"Above
+<span foreground="#888888">────────────────────</span>
+Below"##;
+
+    use pulldown_cmark::{Parser, pango};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_rule_width() {
+    let original = r##"- - -
+"##;
+    let expected = r##"<span foreground="#888888">─────</span>
+"##;
+
+    use pulldown_cmark::{Parser, pango};
+    use pulldown_cmark::pango::RenderOptions;
+
+    let mut s = String::new();
+
+    let opts = RenderOptions { rule_width: 5, ..RenderOptions::default() };
+    let p = Parser::new(&original);
+    pango::push_html_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_strikethrough() {
+    let original = r##"~~Oops~~ *fixed*, ~~**bold**~~
+"##;
+    let expected = r##"<s>Oops</s> <i>fixed</i>, <s><b>bold</b></s>"##;
+
+    use pulldown_cmark::{Parser, pango, OPTION_ENABLE_STRIKETHROUGH};
+
+    let mut s = String::new();
+
+    let p = Parser::new_ext(&original, OPTION_ENABLE_STRIKETHROUGH);
+    pango::push_html(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn test_spoiler() {
+    let original = r##"Ending: ||*everyone* lives||
+"##;
+    let expected = r##"Ending: <span foreground="#000000" background="#000000"><i>everyone</i> lives</span>"##;
+
+    use pulldown_cmark::{Parser, pango, OPTION_ENABLE_SPOILERS};
+    use pulldown_cmark::pango::RenderOptions;
+
+    let mut s = String::new();
+
+    let opts = RenderOptions { spoiler_color: "#000000".to_string(), ..RenderOptions::default() };
+    let p = Parser::new_ext(&original, OPTION_ENABLE_SPOILERS);
+    pango::push_html_with(&mut s, p, &opts);
+
+    assert_eq!(expected, s);
+}
@@ -0,0 +1,36 @@
+// Tests for serializing events, with the serde feature.
+
+#![cfg(feature = "serde")]
+
+extern crate pulldown_cmark;
+extern crate serde_json;
+
+#[test]
+fn serde_test_1() {
+    let original = r##"# Heading
+
+| a | b |
+|:--|--:|
+| [link](/url "title") | `code` |
+"##;
+
+    use pulldown_cmark::{Parser, Event, OPTION_ENABLE_TABLES};
+
+    let events: Vec<Event> = Parser::new_ext(original, OPTION_ENABLE_TABLES).collect();
+    let json = serde_json::to_string(&events).unwrap();
+    let parsed: Vec<Event> = serde_json::from_str(&json).unwrap();
+    assert_eq!(events, parsed);
+}
+
+#[test]
+fn serde_test_2() {
+    use pulldown_cmark::{Event, Tag, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES};
+
+    let event = Event::Start(Tag::Link("/url".into(), "".into()));
+    assert_eq!(r#"{"Start":{"Link":["/url",""]}}"#, serde_json::to_string(&event).unwrap());
+
+    let opts = OPTION_ENABLE_TABLES | OPTION_ENABLE_FOOTNOTES;
+    assert_eq!("6", serde_json::to_string(&opts).unwrap());
+    assert_eq!(opts, serde_json::from_str::<Options>("6").unwrap());
+    assert!(serde_json::from_str::<Options>("2147483648").is_err());
+}
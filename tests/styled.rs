@@ -0,0 +1,120 @@
+// Tests for the styled text renderer.
+
+extern crate pulldown_cmark;
+
+#[test]
+fn styled_test_1() {
+    let original = r##"# Title
+
+Some *emphasis*, **strong**, `code` and a [link](https://example.com).
+"##;
+    let expected = r##"Title
+
+Some emphasis, strong, code and a link."##;
+
+    use pulldown_cmark::{Parser, styled};
+    use pulldown_cmark::styled::{Style, StyledRange};
+
+    let mut s = String::new();
+    let mut ranges = Vec::new();
+    styled::push_styled(&mut s, &mut ranges, Parser::new(original));
+    assert_eq!(expected, s);
+    let range = |start, end, style| StyledRange { start: start, end: end, style: style };
+    assert_eq!(vec![
+        range(0, 5, Style::Scale(1.44)),
+        range(0, 5, Style::Bold),
+        range(12, 20, Style::Italic),
+        range(22, 28, Style::Bold),
+        range(30, 34, Style::Monospace),
+        range(41, 45, Style::Link("https://example.com".to_string())),
+    ], ranges);
+}
+
+#[test]
+fn styled_test_2() {
+    let original = r##"> quoted
+>
+> more
+
+* a
+* b
+
+---
+"##;
+    let expected = r##"┃ quoted
+┃
+┃ more
+
+• a
+• b
+
+────────────────────"##;
+
+    use pulldown_cmark::{Parser, styled};
+    use pulldown_cmark::styled::Style;
+
+    let mut s = String::new();
+    let mut ranges = Vec::new();
+    styled::push_styled(&mut s, &mut ranges, Parser::new(original));
+    assert_eq!(expected, s);
+    let muted: Vec<_> = ranges.iter().map(|range| &s[range.start..range.end]).collect();
+    assert_eq!(vec!["┃", "┃", "┃", "────────────────────"], muted);
+    assert!(ranges.iter().all(|range| range.style == Style::Foreground("#888888".to_string())));
+}
+
+#[test]
+fn styled_test_3() {
+    let original = r##"| Name | Value |
+|------|-------|
+| *a*  | 1     |
+"##;
+    let expected = r##"Name | Value
+a | 1"##;
+
+    use pulldown_cmark::{Parser, styled, OPTION_ENABLE_TABLES};
+    use pulldown_cmark::styled::{Style, StyledRange};
+
+    let mut s = String::new();
+    let mut ranges = Vec::new();
+    styled::push_styled(&mut s, &mut ranges, Parser::new_ext(original, OPTION_ENABLE_TABLES));
+    assert_eq!(expected, s);
+    assert_eq!(vec![
+        StyledRange { start: 0, end: 12, style: Style::Bold },
+        StyledRange { start: 13, end: 14, style: Style::Italic },
+    ], ranges);
+}
+
+#[test]
+fn styled_test_4() {
+    let original = "héllo *wörld*";
+
+    use pulldown_cmark::{Parser, styled};
+
+    let mut s = String::from("» ");
+    let mut ranges = Vec::new();
+    styled::push_styled(&mut s, &mut ranges, Parser::new(original));
+    assert_eq!("» héllo wörld", s);
+    assert_eq!((10, 16), (ranges[0].start, ranges[0].end));
+    assert_eq!((8, 13), ranges[0].char_range(&s));
+}
+
+#[test]
+fn styled_test_5() {
+    let original = r##"Ask @bob in #help, not `@code`.
+"##;
+    let expected = r##"Ask @bob in #help, not @code."##;
+
+    use pulldown_cmark::{Parser, MentionKind, OPTION_ENABLE_MENTIONS, styled};
+    use pulldown_cmark::styled::{Style, StyledRange};
+
+    let mut s = String::new();
+    let mut ranges = Vec::new();
+    styled::push_styled(&mut s, &mut ranges, Parser::new_ext(original, OPTION_ENABLE_MENTIONS));
+    assert_eq!(expected, s);
+    let range = |start, end, style| StyledRange { start: start, end: end, style: style };
+    assert_eq!(vec![
+        range(4, 8, Style::Mention(MentionKind::User, "bob".to_string())),
+        range(12, 17, Style::Mention(MentionKind::Channel, "help".to_string())),
+        range(23, 28, Style::Monospace),
+    ], ranges);
+}
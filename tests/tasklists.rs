@@ -0,0 +1,361 @@
+// This file is auto-generated by the build script
+// Please, do not modify it manually
+
+extern crate pulldown_cmark;
+
+
+    #[test]
+    fn tasklists_test_1() {
+        let original = r##"- [ ] deploy
+- [x] test
+"##;
+        let expected = r##"<ul>
+<li><input disabled="" type="checkbox" />
+deploy</li>
+<li><input disabled="" type="checkbox" checked="" />
+test</li>
+</ul>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn tasklists_test_2() {
+        let original = r##"- [X] upper case
+"##;
+        let expected = r##"<ul>
+<li><input disabled="" type="checkbox" checked="" />
+upper case</li>
+</ul>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn tasklists_test_3() {
+        let original = r##"- [ ]
+- [ ]no space
+- [y] other
+- [  ] two spaces
+"##;
+        let expected = r##"<ul>
+<li>[ ]</li>
+<li>[ ]no space</li>
+<li>[y] other</li>
+<li>[  ] two spaces</li>
+</ul>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn tasklists_test_4() {
+        let original = r##"[x] not in a list
+
+- item with [ ] inside
+"##;
+        let expected = r##"<p>[x] not in a list</p>
+<ul>
+<li>item with [ ] inside</li>
+</ul>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn tasklists_test_5() {
+        let original = r##"- [ ]     not code
+"##;
+        let expected = r##"<ul>
+<li><input disabled="" type="checkbox" />
+not code</li>
+</ul>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn tasklists_test_6() {
+        let original = r##"1. [x] done
+2. [ ] todo
+"##;
+        let expected = r##"<ol>
+<li><input disabled="" type="checkbox" checked="" />
+done</li>
+<li><input disabled="" type="checkbox" />
+todo</li>
+</ol>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn tasklists_test_7() {
+        let original = r##"- [ ] outer
+  - [x] inner
+"##;
+        let expected = r##"<ul>
+<li><input disabled="" type="checkbox" />
+outer
+<ul>
+<li><input disabled="" type="checkbox" checked="" />
+inner</li>
+</ul>
+</li>
+</ul>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn tasklists_test_8() {
+        let original = r##"- [x] first
+
+  [ ] second paragraph
+"##;
+        let expected = r##"<ul>
+<li><input disabled="" type="checkbox" checked="" />
+<p>first</p>
+<p>[ ] second paragraph</p>
+</li>
+</ul>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn tasklists_test_9() {
+        let original = r##"> - [ ] quoted
+"##;
+        let expected = r##"<blockquote>
+<ul>
+<li><input disabled="" type="checkbox" />
+quoted</li>
+</ul>
+</blockquote>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn tasklists_test_10() {
+        let original = r##"-
+  [ ] on the next line
+"##;
+        let expected = r##"<ul>
+<li>[ ] on the next line</li>
+</ul>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn tasklists_test_11() {
+        let original = r##"- [ ] *styled* `code`
+"##;
+        let expected = r##"<ul>
+<li><input disabled="" type="checkbox" />
+<em>styled</em> <code>code</code></li>
+</ul>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn tasklists_test_12() {
+        let original = r##"- [ ] ---
+- [x] > q
+- [ ] # h
+- [ ] - x
+"##;
+        let expected = r##"<ul>
+<li>[ ] ---</li>
+<li>[x] &gt; q</li>
+<li>[ ] # h</li>
+<li>[ ] - x</li>
+</ul>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
+
+    #[test]
+    fn tasklists_test_13() {
+        let original = r##"- [ ] ```
+- [x] ~~~
+"##;
+        let expected = r##"<ul>
+<li>[ ] ```</li>
+<li>[x] ~~~</li>
+</ul>
+"##;
+
+        use pulldown_cmark::{Parser, html, Options, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_TASKLISTS};
+
+        let mut s = String::new();
+
+        let mut opts = Options::empty();
+        opts.insert(OPTION_ENABLE_TABLES);
+        opts.insert(OPTION_ENABLE_FOOTNOTES);
+        opts.insert(OPTION_ENABLE_TASKLISTS);
+
+        let p = Parser::new_ext(&original, opts);
+        html::push_html(&mut s, p);
+
+        assert_eq!(expected, s);
+    }
\ No newline at end of file
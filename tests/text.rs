@@ -0,0 +1,173 @@
+// Tests for the plain text renderer.
+
+extern crate pulldown_cmark;
+
+#[test]
+fn text_test_1() {
+    let original = r##"# Release *notes*
+
+Fixed a **crash** in `parse`, see [the issue](https://example.com/1).
+Mail <team@example.com> or visit <https://example.com>.
+"##;
+    let expected = r##"Release notes
+
+Fixed a crash in parse, see the issue (https://example.com/1). Mail team@example.com or visit https://example.com."##;
+
+    use pulldown_cmark::{Parser, text};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    text::push_text(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn text_test_2() {
+    let original = r##"3. first
+   continued  
+   on two lines
+4. second
+   - nested
+   - bullets
+
+after
+"##;
+    let expected = r##"3. first continued
+   on two lines
+4. second
+   • nested
+   • bullets
+
+after"##;
+
+    use pulldown_cmark::{Parser, text};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    text::push_text(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn text_test_3() {
+    let original = r##"Someone wrote:
+
+> It's &lt;fine&gt; &copy; &#65;
+>
+> > Really?
+
+<b>Raw</b> HTML is <i>dropped</i>.
+"##;
+    let expected = r##"Someone wrote:
+
+  It's <fine> © A
+
+    Really?
+
+Raw HTML is dropped."##;
+
+    use pulldown_cmark::{Parser, text};
+
+    let mut s = String::new();
+
+    let p = Parser::new(&original);
+    text::push_text(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn text_test_4() {
+    let original = r##"| Name | Size |
+|------|------|
+| a    | 1    |
+
+Note[^n] ![a cat](cat.png)
+
+```
+let x = 1;
+```
+
+[^n]: Small print.
+"##;
+    let expected = r##"Name | Size
+a | 1
+
+Note[1] a cat
+
+let x = 1;
+
+[1] Small print."##;
+
+    use pulldown_cmark::{Parser, text, OPTION_ENABLE_TABLES, OPTION_ENABLE_FOOTNOTES};
+
+    let mut s = String::new();
+
+    let p = Parser::new_ext(&original, OPTION_ENABLE_TABLES | OPTION_ENABLE_FOOTNOTES);
+    text::push_text(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn text_test_5() {
+    let original = r##"- [ ] deploy the new build
+  to staging
+- [x] test
+
+  again
+"##;
+    let expected = r##"• ☐ deploy the new build to staging
+• ☑ test
+
+    again"##;
+
+    use pulldown_cmark::{Parser, text, OPTION_ENABLE_TASKLISTS};
+
+    let mut s = String::new();
+
+    let p = Parser::new_ext(&original, OPTION_ENABLE_TASKLISTS);
+    text::push_text(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn text_test_6() {
+    let original = r##"The killer is ||the *butler*[^1]||.
+
+[^1]: Obviously.
+"##;
+    let expected = r##"The killer is [spoiler].
+
+[1] Obviously."##;
+
+    use pulldown_cmark::{Parser, text, OPTION_ENABLE_FOOTNOTES, OPTION_ENABLE_SPOILERS};
+
+    let mut s = String::new();
+
+    let p = Parser::new_ext(&original, OPTION_ENABLE_FOOTNOTES | OPTION_ENABLE_SPOILERS);
+    text::push_text(&mut s, p);
+
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn text_test_7() {
+    let original = r##"<!-- nothing to show -->
+"##;
+    let expected = "Summary: ";
+
+    use pulldown_cmark::{Parser, text};
+
+    let mut s = String::from("Summary: ");
+
+    let p = Parser::new(&original);
+    text::push_text(&mut s, p);
+
+    assert_eq!(expected, s);
+}
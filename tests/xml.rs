@@ -0,0 +1,128 @@
+// Tests for the XML renderer.
+
+extern crate pulldown_cmark;
+
+#[test]
+fn xml_test_1() {
+    let original = r##"Hello *world*,
+with a [link](/url "title") and `code`.
+
+***
+"##;
+    let expected = r##"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE document SYSTEM "CommonMark.dtd">
+<document xmlns="http://commonmark.org/xml/1.0">
+  <paragraph>
+    <text xml:space="preserve">Hello </text>
+    <emph>
+      <text xml:space="preserve">world</text>
+    </emph>
+    <text xml:space="preserve">,</text>
+    <softbreak />
+    <text xml:space="preserve">with a </text>
+    <link destination="/url" title="title">
+      <text xml:space="preserve">link</text>
+    </link>
+    <text xml:space="preserve"> and </text>
+    <code xml:space="preserve">code</code>
+    <text xml:space="preserve">.</text>
+  </paragraph>
+  <thematic_break />
+</document>
+"##;
+
+    use pulldown_cmark::{Parser, xml};
+
+    let mut s = String::new();
+    xml::push_xml(&mut s, Parser::new(original));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn xml_test_2() {
+    let original = r##"3. tight
+4. list
+
+text
+
+- loose
+
+- list
+  > quoted
+"##;
+    let expected = r##"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE document SYSTEM "CommonMark.dtd">
+<document xmlns="http://commonmark.org/xml/1.0">
+  <list type="ordered" start="3" tight="true">
+    <item>
+      <paragraph>
+        <text xml:space="preserve">tight</text>
+      </paragraph>
+    </item>
+    <item>
+      <paragraph>
+        <text xml:space="preserve">list</text>
+      </paragraph>
+    </item>
+  </list>
+  <paragraph>
+    <text xml:space="preserve">text</text>
+  </paragraph>
+  <list type="bullet" tight="false">
+    <item>
+      <paragraph>
+        <text xml:space="preserve">loose</text>
+      </paragraph>
+    </item>
+    <item>
+      <paragraph>
+        <text xml:space="preserve">list</text>
+      </paragraph>
+      <block_quote>
+        <paragraph>
+          <text xml:space="preserve">quoted</text>
+        </paragraph>
+      </block_quote>
+    </item>
+  </list>
+</document>
+"##;
+
+    use pulldown_cmark::{Parser, xml};
+
+    let mut s = String::new();
+    xml::push_xml(&mut s, Parser::new(original));
+    assert_eq!(expected, s);
+}
+
+#[test]
+fn xml_test_3() {
+    let original = r##"#
+
+```rust
+a < b
+```
+
+<div>
+raw & html
+</div>
+"##;
+    let expected = r##"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE document SYSTEM "CommonMark.dtd">
+<document xmlns="http://commonmark.org/xml/1.0">
+  <heading level="1" />
+  <code_block info="rust" xml:space="preserve">a &lt; b
+</code_block>
+  <html_block xml:space="preserve">&lt;div&gt;
+raw &amp; html
+&lt;/div&gt;
+</html_block>
+</document>
+"##;
+
+    use pulldown_cmark::{Parser, xml};
+
+    let mut s = String::new();
+    xml::push_xml(&mut s, Parser::new(original));
+    assert_eq!(expected, s);
+}
@@ -0,0 +1,120 @@
+# Copyright 2015 Google Inc. All rights reserved.
+#
+# Permission is hereby granted, free of charge, to any person obtaining a copy
+# of this software and associated documentation files (the "Software"), to deal
+# in the Software without restriction, including without limitation the rights
+# to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+# copies of the Software, and to permit persons to whom the Software is
+# furnished to do so, subject to the following conditions:
+#
+# The above copyright notice and this permission notice shall be included in
+# all copies or substantial portions of the Software.
+#
+# THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+# IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+# FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+# AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+# LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+# OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+# THE SOFTWARE.
+
+# The table can be made from either of:
+#
+#   gemoji's db/emoji.json, from
+#   https://raw.githubusercontent.com/github/gemoji/master/db/emoji.json
+#
+#   rich's rich/_emoji_codes.py, which has the gemoji aliases along with the
+#   CLDR short names (like alien_monster for the gemoji space_invader)
+#
+# The checked-in src/emoji.rs is made from rich's _emoji_codes.py, which is the
+# same from rich 11 to 14.
+#
+# Usage: python tools/mk_emoji.py emoji.json > src/emoji.rs
+#        python tools/mk_emoji.py _emoji_codes.py > src/emoji.rs
+
+import json
+import re
+import struct
+import sys
+
+# names that can be written as a shortcode; a few in rich's table, like __1
+# for thumbs down, are leftovers that no one would type
+SHORTCODE = re.compile(r'^[a-z0-9+-][a-z0-9_+-]*$')
+
+def codepoints(s):
+    # narrow Python builds split astral characters into surrogate pairs
+    data = s.encode('utf-32-be')
+    return struct.unpack('>%iI' % (len(data) / 4), data)
+
+def read_gemoji(text):
+    emoji = {}
+    for entry in json.loads(text):
+        for alias in entry['aliases']:
+            emoji[alias] = entry['emoji']
+    return emoji
+
+def read_rich(text):
+    emoji = {}
+    for m in re.finditer(r'^    "([^"]*)": "([^"]*)",$', text.decode('utf-8'), re.M):
+        # some characters, like the zero width joiners, are Python escapes
+        value = re.sub(r'\\(u[0-9a-fA-F]{4}|U[0-9a-fA-F]{8})',
+                       lambda e: e.group(0).decode('unicode_escape'), m.group(2))
+        emoji[m.group(1)] = value
+    return emoji
+
+def main(args):
+    text = file(args[1]).read()
+    if args[1].endswith('.py'):
+        emoji = read_rich(text)
+    else:
+        emoji = read_gemoji(text)
+    for name in emoji.keys():
+        if not SHORTCODE.match(name):
+            del emoji[name]
+    shortcodes = emoji.keys()
+    shortcodes.sort()
+    print """// Copyright 2015 Google Inc. All rights reserved.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! The built-in table of emoji shortcodes, such as `smile` in `:smile:`.
+
+// Autogenerated by mk_emoji.py
+
+static SHORTCODES: [&'static str; %i] = [""" % len(shortcodes)
+    for s in shortcodes:
+        print "        \"" + s + "\","
+    print """    ];
+
+static EMOJI: [&'static str; %i] = [""" % len(shortcodes)
+    for s in shortcodes:
+        value = ''.join(['\u{%04X}' % cp for cp in codepoints(emoji[s])])
+        print "        \"" + value + "\","
+    print """    ];
+
+/// The emoji for `shortcode`, given without its colons, if there is one.
+///
+/// This is the table used to resolve shortcodes when a parser has no
+/// resolver of its own, so a resolver can fall back on it.
+pub fn get_emoji(shortcode: &str) -> Option<&'static str> {
+    SHORTCODES.binary_search(&shortcode).ok().map(|i| EMOJI[i])
+}
+"""
+
+main(sys.argv)